@@ -0,0 +1,61 @@
+//! Parse, part 1 and part 2 of every registered day against its real input.
+//!
+//! Benchmarks are named `yYYYY/dayNN/<step>`, so a single day is selected with
+//! `cargo bench --bench days -- y2023/day05/`. Parts marked as slow in
+//! `answers/<year>.toml` are skipped unless `AOC_SLOW` is set.
+
+use std::{env, fs, hint::black_box, time::Duration};
+
+use aoc23::{
+    answers::{self, Answers},
+    inputs,
+    solution::{Day, Part, Run},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_day(c: &mut Criterion, day: &Day, answers: &Answers) {
+    let path = inputs::path(day.year, day.day);
+    let Ok(input) = fs::read_to_string(&path) else {
+        eprintln!("skipping day {}: missing input {}", day.day, path.display());
+        return;
+    };
+    let skip = |part| answers.is_slow(day.year, day.day, part) && env::var_os("AOC_SLOW").is_none();
+    // A run parses the input again every time and times both steps apart, so
+    // only the step being benchmarked counts.
+    let timed = |part: Part, step: fn(&Run) -> Duration| {
+        let input = &input;
+        move |iters: u64| -> Duration {
+            (0..iters)
+                .map(|_| step(&day.run(black_box(input), part).unwrap()))
+                .sum()
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("y{}/day{:02}", day.year, day.day));
+    let parts: Vec<Part> = day
+        .parts()
+        .iter()
+        .copied()
+        .filter(|&part| !skip(part))
+        .collect();
+    if let Some(&part) = parts.first() {
+        let parse = timed(part, |run| run.parse_time);
+        group.bench_function("parse", |b| b.iter_custom(&parse));
+    }
+    for part in parts {
+        let solve = timed(part, |run| run.solve_time);
+        group.bench_function(format!("part{part}"), |b| b.iter_custom(&solve));
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    let answers = Answers::load(answers::DEFAULT_DIR).unwrap_or_default();
+
+    for day in aoc23::days() {
+        bench_day(c, day, &answers);
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
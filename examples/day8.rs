@@ -1,6 +1,6 @@
-use aoc23::day8::solve_part2;
+use aoc23::{solution::Solution, y2023::day8::Day8};
 
 pub fn main() {
     let input = include_str!("../input/2023/day8.txt");
-    println!("{}", solve_part2(input));
+    println!("{}", Day8::solve_part2(input).unwrap());
 }
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc23::{solution::Solution, y2023::day1::Day1};
+use libfuzzer_sys::fuzz_target;
+
+// Day 1 only interprets its input while solving, both parts are cheap.
+fuzz_target!(|input: &str| {
+    let _ = Day1::solve_part1(input);
+    let _ = Day1::solve_part2(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc23::{solution::Solution, y2023::day3::Day3};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day3::parse(input);
+});
@@ -0,0 +1,288 @@
+//! Step by step replays of the days that walk through their input, played in
+//! the terminal or dumped as plain text.
+
+use std::{
+    io::{self, IsTerminal, Write},
+    ops::ControlFlow,
+    time::{Duration, Instant},
+};
+
+use anyhow::bail;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, ClearType},
+    QueueableCommand,
+};
+
+use crate::{common::grid::Coord, solution::Day};
+
+/// What the walk looks like after some number of steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub lines: Vec<String>,
+    /// Where the walk is at, as a line and a character in that line.
+    pub highlight: Option<Coord>,
+    /// Shown below the picture, like the step counter.
+    pub status: String,
+}
+
+/// Receives the frames of an animation, breaking to stop it early.
+pub type Show<'a> = dyn FnMut(Frame) -> ControlFlow<()> + 'a;
+
+/// Frames are cut down to this when there is no terminal to fit them to.
+const DUMP_SIZE: (usize, usize) = (80, 24);
+
+/// The part of `frame` that fits in `width` by `height` characters, moved so
+/// the highlight stays in the middle whenever the frame is bigger than that.
+pub fn crop(frame: &Frame, width: usize, height: usize) -> Frame {
+    let start = |len: usize, size: usize, at: Option<usize>| {
+        let start = at.map_or(0, |at| at.saturating_sub(size / 2));
+        start.min(len.saturating_sub(size))
+    };
+    let longest = frame
+        .lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let top = start(frame.lines.len(), height, frame.highlight.map(|at| at.row));
+    let left = start(longest, width, frame.highlight.map(|at| at.col));
+
+    Frame {
+        lines: frame
+            .lines
+            .iter()
+            .skip(top)
+            .take(height)
+            .map(|line| line.chars().skip(left).take(width).collect())
+            .collect(),
+        highlight: frame
+            .highlight
+            .and_then(|at| {
+                Some(Coord::new(
+                    at.row.checked_sub(top)?,
+                    at.col.checked_sub(left)?,
+                ))
+            })
+            .filter(|at| at.row < height && at.col < width),
+        status: frame.status.clone(),
+    }
+}
+
+/// Plays the walk of `day` through `input` in the terminal at `speed` steps
+/// per second, or writes every frame to stdout when that's not a terminal.
+pub fn play(day: &Day, input: &str, speed: f64) -> anyhow::Result<()> {
+    if speed.is_nan() || speed <= 0.0 {
+        bail!("the speed has to be above 0");
+    }
+    if !io::stdout().is_terminal() {
+        let mut out = io::stdout().lock();
+        let mut res = Ok(());
+        let animated = day.animate(input, &mut |frame| match dump(&mut out, &frame) {
+            Ok(()) => ControlFlow::Continue(()),
+            // Whoever reads has seen enough, like `head`.
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ControlFlow::Break(()),
+            Err(e) => {
+                res = Err(e);
+                ControlFlow::Break(())
+            }
+        })?;
+        if !animated {
+            bail!("day {} of {} can't be animated", day.day, day.year);
+        }
+        return Ok(res?);
+    }
+
+    let mut player = Player::start(speed)?;
+    let mut res = Ok(());
+    let animated = day.animate(input, &mut |frame| match player.show(&frame) {
+        Ok(flow) => flow,
+        Err(e) => {
+            res = Err(e);
+            ControlFlow::Break(())
+        }
+    })?;
+    if !animated {
+        drop(player);
+        bail!("day {} of {} can't be animated", day.day, day.year);
+    }
+    res?;
+    player.wait_for_key()
+}
+
+/// Writes `frame` as plain text, followed by its status and an empty line.
+fn dump(out: &mut impl Write, frame: &Frame) -> io::Result<()> {
+    let (width, height) = DUMP_SIZE;
+    for line in crop(frame, width, height).lines {
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "{}\n", frame.status)
+}
+
+/// The terminal while an animation plays. Puts the terminal back the way it
+/// was when dropped.
+struct Player {
+    out: io::Stdout,
+    /// Steps per second.
+    speed: f64,
+    paused: bool,
+    last: Instant,
+    /// Set when quitting halfway, so there is no waiting for a key at the end.
+    quit: bool,
+}
+
+const HELP: &str = "space: pause, n: next step, +/-: speed, q: quit";
+
+impl Player {
+    fn start(speed: f64) -> io::Result<Self> {
+        let mut out = io::stdout();
+        terminal::enable_raw_mode()?;
+        out.queue(terminal::EnterAlternateScreen)?
+            .queue(cursor::Hide)?
+            .flush()?;
+        Ok(Player {
+            out,
+            speed,
+            paused: false,
+            last: Instant::now(),
+            quit: false,
+        })
+    }
+
+    /// Draws `frame`, then waits until it's time for the next one while
+    /// handling the keys pressed in the meantime.
+    fn show(&mut self, frame: &Frame) -> io::Result<ControlFlow<()>> {
+        self.draw(frame)?;
+        loop {
+            let due = self.last + Duration::from_secs_f64(1.0 / self.speed);
+            let wait = if self.paused {
+                Duration::from_millis(100)
+            } else {
+                due.saturating_duration_since(Instant::now())
+            };
+            if !self.paused && wait.is_zero() {
+                self.last = Instant::now();
+                return Ok(ControlFlow::Continue(()));
+            }
+            if !event::poll(wait)? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('n') | KeyCode::Right if self.paused => {
+                    return Ok(ControlFlow::Continue(()))
+                }
+                KeyCode::Char('+' | '=') => self.speed = (self.speed * 2.0).min(10_000.0),
+                KeyCode::Char('-') => self.speed = (self.speed / 2.0).max(0.25),
+                _ if is_quit(key) => {
+                    self.quit = true;
+                    return Ok(ControlFlow::Break(()));
+                }
+                _ => continue,
+            }
+            self.draw(frame)?;
+        }
+    }
+
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        let (width, height) = match terminal::size()? {
+            (0, _) | (_, 0) => DUMP_SIZE,
+            (cols, rows) => (cols as usize, rows as usize),
+        };
+        // Two lines go to the status.
+        let height = height.saturating_sub(2).max(1);
+        let frame = crop(frame, width, height);
+
+        self.out.queue(cursor::MoveTo(0, 0))?;
+        for (row, line) in frame.lines.iter().enumerate() {
+            match frame.highlight.filter(|at| at.row == row) {
+                Some(at) => {
+                    let before: String = line.chars().take(at.col).collect();
+                    let current: String = line.chars().skip(at.col).take(1).collect();
+                    let after: String = line.chars().skip(at.col + 1).collect();
+                    self.out
+                        .queue(Print(before))?
+                        .queue(SetAttribute(Attribute::Reverse))?
+                        .queue(Print(current))?
+                        .queue(SetAttribute(Attribute::Reset))?
+                        .queue(Print(after))?;
+                }
+                None => {
+                    self.out.queue(Print(line))?;
+                }
+            }
+            self.out
+                .queue(terminal::Clear(ClearType::UntilNewLine))?
+                .queue(Print("\r\n"))?;
+        }
+        let state = if self.paused { ", paused" } else { "" };
+        self.out
+            .queue(terminal::Clear(ClearType::FromCursorDown))?
+            .queue(Print(format!("{}\r\n", frame.status)))?
+            .queue(Print(format!("{} steps/s{state} ({HELP})", self.speed)))?
+            .flush()
+    }
+
+    /// Keeps the last frame up until a key is pressed.
+    fn wait_for_key(&mut self) -> anyhow::Result<()> {
+        if self.quit {
+            return Ok(());
+        }
+        self.out.queue(Print(" - done, press any key"))?.flush()?;
+        loop {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        let _ = self
+            .out
+            .queue(cursor::Show)
+            .and_then(|out| out.queue(terminal::LeaveAlternateScreen))
+            .and_then(|out| out.flush());
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn is_quit(key: KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+        || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crops_around_the_highlight() {
+        let frame = Frame {
+            lines: (0..10).map(|row| format!("{row}abcdefghi")).collect(),
+            highlight: Some(Coord::new(8, 5)),
+            status: "step 3".to_string(),
+        };
+        let cropped = crop(&frame, 4, 3);
+        assert_eq!(cropped.lines, ["cdef"; 3]);
+        assert_eq!(cropped.highlight, Some(Coord::new(1, 2)));
+        assert_eq!(crop(&frame, 20, 20), frame);
+
+        let mut out = Vec::new();
+        dump(&mut out, &frame).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .ends_with("9abcdefghi\nstep 3\n\n"));
+    }
+}
@@ -0,0 +1,147 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{anyhow, bail, Context};
+use toml::{Table, Value};
+
+use crate::solution::Part;
+
+/// Holds one `<year>.toml` per year.
+pub const DEFAULT_DIR: &str = "answers";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+    slow: Vec<Part>,
+}
+
+/// The accepted answers for the real puzzle inputs. Every year has its own
+/// file with one `[dayN]` table per day:
+///
+/// ```toml
+/// [day5]
+/// part1 = 331445006
+/// part2 = 6472060
+/// slow = [2]
+/// ```
+///
+/// Parts listed in `slow` take too long to be checked on every test run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<(u16, u8), DayAnswers>,
+}
+
+pub(crate) fn answer(value: &Value) -> anyhow::Result<String> {
+    match value {
+        Value::Integer(n) => Ok(n.to_string()),
+        Value::String(s) => Ok(s.clone()),
+        v => bail!("expected a number or string as answer, got {v}"),
+    }
+}
+
+impl Answers {
+    /// Loads every `<year>.toml` in `dir`.
+    pub fn load(dir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let dir = dir.as_ref();
+        let mut res = Answers::default();
+        let entries =
+            fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            let Some(year) = path
+                .file_stem()
+                .filter(|_| path.extension().is_some_and(|ext| ext == "toml"))
+                .and_then(|stem| stem.to_str()?.parse::<u16>().ok())
+            else {
+                continue;
+            };
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            res.add_year(year, &content)
+                .with_context(|| format!("failed to parse {}", path.display()))?;
+        }
+        Ok(res)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        let answers = self.days.get(&(year, day))?;
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    pub fn is_slow(&self, year: u16, day: u8, part: Part) -> bool {
+        self.days
+            .get(&(year, day))
+            .is_some_and(|answers| answers.slow.contains(&part))
+    }
+
+    /// Adds the answers of `year` from the content of its file.
+    pub fn add_year(&mut self, year: u16, content: &str) -> anyhow::Result<()> {
+        for (key, value) in content.parse::<Table>()? {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| anyhow!("expected a `[dayN]` table, got `[{key}]`"))?;
+            let Value::Table(table) = value else {
+                bail!("expected `{key}` to be a table");
+            };
+
+            let mut answers = DayAnswers::default();
+            for (key, value) in table {
+                match key.as_str() {
+                    "part1" => answers.part1 = Some(answer(&value)?),
+                    "part2" => answers.part2 = Some(answer(&value)?),
+                    "slow" => {
+                        answers.slow = value
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .map(|part| {
+                                part.as_integer()
+                                    .and_then(|part| u8::try_from(part).ok())
+                                    .ok_or_else(|| anyhow!("invalid part {part}"))
+                                    .and_then(|part| Part::try_from(part).map_err(|e| anyhow!(e)))
+                            })
+                            .collect::<anyhow::Result<_>>()?
+                    }
+                    k => bail!("unexpected key `{k}` for day {day}"),
+                }
+            }
+            self.days.insert((year, day), answers);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let mut answers = Answers::default();
+        answers
+            .add_year(
+                2023,
+                "[day1]\npart1 = 142\npart2 = \"abc\"\n\n[day5]\npart1 = 35\nslow = [2]\n",
+            )
+            .unwrap();
+
+        assert_eq!(answers.get(2023, 1, Part::One), Some("142"));
+        assert_eq!(answers.get(2023, 1, Part::Two), Some("abc"));
+        assert_eq!(answers.get(2023, 5, Part::Two), None);
+        assert_eq!(answers.get(2022, 1, Part::One), None);
+        assert!(answers.is_slow(2023, 5, Part::Two));
+        assert!(!answers.is_slow(2023, 1, Part::Two));
+    }
+
+    #[test]
+    fn reject_unknown_keys() {
+        let mut answers = Answers::default();
+        assert!(answers.add_year(2023, "[day1]\npart3 = 1").is_err());
+        assert!(answers.add_year(2023, "[first]\npart1 = 1").is_err());
+    }
+}
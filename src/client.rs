@@ -0,0 +1,221 @@
+//! A minimal HTTP client for adventofcode.com.
+//!
+//! Every request identifies itself with a descriptive User-Agent and waits at
+//! least [`Client::with_min_interval`] after the previous one, as asked for by
+//! the site's automation guidelines.
+
+use std::{
+    env,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = concat!(
+    "aoc23/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal Advent of Code runner; requests are cached and rate limited)"
+);
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// The session cookie of a logged in browser.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.into(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Reads the session from `AOC_SESSION` and, if set, the base URL from
+    /// `AOC_BASE_URL`.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let session = env::var(SESSION_ENV)
+            .with_context(|| format!("{SESSION_ENV} must be set to your session cookie"))?;
+        let client = Client::new(session.trim());
+        Ok(match env::var(BASE_URL_ENV) {
+            Ok(url) => client.with_base_url(url),
+            Err(_) => client,
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Blocks until `min_interval` has passed since the previous request.
+    fn wait_turn(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    pub fn get(&self, path: &str) -> anyhow::Result<Response> {
+        self.wait_turn();
+        let url = self.url(path);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie())
+            .call()
+            .map_err(|e| anyhow!("GET {url} failed: {e}"))?;
+        read_response(response)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<Response> {
+        self.wait_turn();
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form(form.iter().copied())
+            .map_err(|e| anyhow!("POST {url} failed: {e}"))?;
+        read_response(response)
+    }
+}
+
+fn read_response(mut response: ureq::http::Response<ureq::Body>) -> anyhow::Result<Response> {
+    Ok(Response {
+        status: response.status().as_u16(),
+        body: response.body_mut().read_to_string()?,
+    })
+}
+
+/// A stand-in for the real site, answering requests with canned responses.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves `responses` in order, one per connection, and returns every
+    /// request it received, head and body, once all are served.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifies_itself() {
+        let (url, server) = mock::serve(vec![(200, "hi".to_string())]);
+        let client = Client::new("secret").with_base_url(format!("{url}/"));
+
+        let response = client.get("/2023/day/1/input").unwrap();
+        assert_eq!((response.status, response.body.as_str()), (200, "hi"));
+
+        let request = server.join().unwrap().remove(0);
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        let request = request.to_lowercase();
+        assert!(request.contains("cookie: session=secret\r\n"));
+        assert!(request.contains(&format!("user-agent: {}\r\n", USER_AGENT.to_lowercase())));
+    }
+
+    #[test]
+    fn rate_limited() {
+        let (url, server) = mock::serve(vec![(200, String::new()), (404, String::new())]);
+        let client = Client::new("secret")
+            .with_base_url(url)
+            .with_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.get("/").unwrap();
+        assert_eq!(client.get("/").unwrap().status, 404);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+}
@@ -1,3 +1,9 @@
+pub mod geom;
+pub mod grid;
+pub mod parse;
+
+use std::{fmt, time::Duration};
+
 pub fn human_readable_numbers<T: Into<u64>>(value: T) -> String {
     let value: u64 = value.into();
     match value {
@@ -9,3 +15,129 @@ pub fn human_readable_numbers<T: Into<u64>>(value: T) -> String {
     }
 }
 
+/// `value` in the largest unit that keeps it under 1000, rounded to a tenth
+/// of that unit. Minutes and seconds from a minute on.
+pub fn human_readable_duration(value: Duration) -> String {
+    let nanos = value.as_nanos();
+    if nanos < 1_000 {
+        return format!("{nanos}ns");
+    }
+    // Rounding can make it 1000 of a unit, which is 1 of the next.
+    for (unit, name) in [(1_000, "µs"), (1_000_000, "ms"), (1_000_000_000, "s")] {
+        let tenths = (nanos + unit / 20) / (unit / 10);
+        if tenths < 10_000 && (name != "s" || tenths < 600) {
+            return format!("{}.{}{name}", tenths / 10, tenths % 10);
+        }
+    }
+    let secs = (nanos + 500_000_000) / 1_000_000_000;
+    format!("{}m{}s", secs / 60, secs % 60)
+}
+
+/// An input that could not be parsed, pointing at the offending spot.
+///
+/// `line` and `column` are 1-based, `snippet` is the full offending line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `token`, which has to be a slice of `input`.
+    pub fn at(day: u8, input: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .expect("token is not part of the input");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+
+        ParseError {
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for something missing from the input as a whole,
+    /// pointing just past its end.
+    pub fn at_end(day: u8, input: &str, message: impl Into<String>) -> Self {
+        let trimmed = input.trim_end();
+        Self::at(day, input, &trimmed[trimmed.len()..], message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(human_readable_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(
+            human_readable_duration(Duration::from_micros(1_500)),
+            "1.5ms"
+        );
+        assert_eq!(
+            human_readable_duration(Duration::from_nanos(1_449_999)),
+            "1.4ms"
+        );
+        assert_eq!(
+            human_readable_duration(Duration::from_nanos(999_950)),
+            "1.0ms"
+        );
+        assert_eq!(human_readable_duration(Duration::from_secs(42)), "42.0s");
+        assert_eq!(
+            human_readable_duration(Duration::from_millis(59_960)),
+            "1m0s"
+        );
+        assert_eq!(human_readable_duration(Duration::from_secs(125)), "2m5s");
+    }
+
+    #[test]
+    fn parse_error_location() {
+        let input = "first line\nsecond lXne\nthird";
+        let token = &input[19..20];
+        let err = ParseError::at(1, input, token, "unexpected character 'X'");
+
+        assert_eq!((err.line, err.column), (2, 9));
+        assert_eq!(err.snippet, "second lXne");
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 9: unexpected character 'X'\n  |\n2 | second lXne\n  |         ^"
+        );
+    }
+
+    #[test]
+    fn parse_error_at_end() {
+        let err = ParseError::at_end(1, "abc\n\n", "missing section");
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.snippet, "abc");
+    }
+}
@@ -0,0 +1,252 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use super::grid::Coord;
+
+/// A position on an unbounded plane. `y` grows downwards, matching the rows
+/// of a [`Grid`](super::grid::Grid).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The difference between two [`Point`]s.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The grid position of this point, `None` if either axis is negative.
+    pub fn to_coord(self) -> Option<Coord> {
+        Some(Coord::new(
+            usize::try_from(self.y).ok()?,
+            usize::try_from(self.x).ok()?,
+        ))
+    }
+}
+
+impl From<Coord> for Point {
+    fn from(coord: Coord) -> Self {
+        Point {
+            x: coord.col.try_into().expect("column out of range"),
+            y: coord.row.try_into().expect("row out of range"),
+        }
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vector { x, y }
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: i64) -> Vector {
+        Vector::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four directions along the axes, north being up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, starting at north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::South => Vector::new(0, 1),
+            Direction::West => Vector::new(-1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction) -> Point {
+        self + rhs.vector()
+    }
+}
+
+/// One of the eight directions including the diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise, starting at north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction8::North => Vector::new(0, -1),
+            Direction8::NorthEast => Vector::new(1, -1),
+            Direction8::East => Vector::new(1, 0),
+            Direction8::SouthEast => Vector::new(1, 1),
+            Direction8::South => Vector::new(0, 1),
+            Direction8::SouthWest => Vector::new(-1, 1),
+            Direction8::West => Vector::new(-1, 0),
+            Direction8::NorthWest => Vector::new(-1, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction8) -> Point {
+        self + rhs.vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().vector(), -dir.vector());
+            assert_eq!(Direction8::from(dir).vector(), dir.vector());
+        }
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction8::West.turn_right(), Direction8::NorthWest);
+        assert_eq!(Direction8::NorthEast.opposite(), Direction8::SouthWest);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(a + (b - a), b);
+    }
+
+    #[test]
+    fn grid_coords() {
+        assert_eq!(Point::new(3, 1).to_coord(), Some(Coord::new(1, 3)));
+        assert_eq!(Point::new(0, -1).to_coord(), None);
+        assert_eq!(Point::from(Coord::new(1, 3)), Point::new(3, 1));
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use super::{parse, ParseError};
+
+/// A position in a [`Grid`], with row 0 at the top.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Coord { row, col }
+    }
+
+    /// Moves by `d_row` and `d_col`, or `None` when that leaves the first
+    /// quadrant. Use [`Grid::contains`] for the other bounds.
+    pub fn offset(self, d_row: isize, d_col: isize) -> Option<Self> {
+        Some(Coord {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `cells` doesn't fill whole rows of `width`.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0, "a grid needs at least one column");
+        assert_eq!(cells.len() % width, 0, "cells have to fill whole rows");
+        Grid { width, cells }
+    }
+
+    /// Parses one row per non-blank line, each character through `T::try_from`.
+    /// Lines are trimmed, and all of them need the same number of cells.
+    pub fn parse(day: u8, input: &str) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        parse::finish(day, input, parse::grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.row < self.height() && coord.col < self.width
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.contains(coord)
+            .then(|| &self.cells[coord.row * self.width + coord.col])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.contains(coord)
+            .then(|| &mut self.cells[coord.row * self.width + coord.col])
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| (Coord::new(idx / width, idx % width), cell))
+    }
+
+    /// The position of the first cell matching `predicate`, row by row.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coord> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.col(col))
+    }
+
+    /// The in-bounds neighbours above, right of, below and left of `coord`.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(coord, &NEIGHBOURS4)
+    }
+
+    /// Like [`Grid::neighbours4`], but including the diagonals, clockwise
+    /// starting at the top.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbours(coord, &NEIGHBOURS8)
+    }
+
+    fn neighbours(
+        &self,
+        coord: Coord,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Coord> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&(d_row, d_col)| coord.offset(d_row, d_col))
+            .filter(|&coord| self.contains(coord))
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::new(3, (1..=6).collect())
+    }
+
+    #[test]
+    fn parse() {
+        let grid = Grid::<char>::parse(1, "ab\n  cd\n\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Coord::new(1, 0)], 'c');
+
+        let err = Grid::<char>::parse(1, "abc\nde").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(Grid::<char>::parse(1, "\n").is_err());
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let grid = digits();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        let cols: Vec<Vec<_>> = grid.cols().map(|col| col.copied().collect()).collect();
+        assert_eq!(cols, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn bounds() {
+        let grid = digits();
+        assert_eq!(grid.get(Coord::new(1, 2)), Some(&6));
+        assert_eq!(grid.get(Coord::new(2, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, 3)), None);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits();
+        let corner = Coord::new(0, 0);
+        assert_eq!(
+            grid.neighbours4(corner).collect::<Vec<_>>(),
+            [Coord::new(0, 1), Coord::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+        assert_eq!(grid.neighbours8(Coord::new(1, 1)).count(), 5);
+    }
+}
@@ -0,0 +1,314 @@
+//! nom combinators for the shapes that keep coming back in puzzle inputs.
+//!
+//! Line based parsers trim every line and skip blank ones, like the hand
+//! written parsers did. Custom errors are raised as strings through
+//! `map_res_cut` or [`fail`], and [`finish`] turns the error tree into a
+//! [`ParseError`] pointing at the deepest failure.
+
+use std::{fmt::Display, str::FromStr};
+
+use nom::{
+    character::complete::{alphanumeric1, char, multispace0, not_line_ending, space0, space1},
+    combinator::all_consuming,
+    error::FromExternalError,
+    multi::separated_list1,
+    sequence::{delimited, separated_pair, terminated},
+    IResult, Parser,
+};
+use nom_supreme::{
+    error::{BaseErrorKind, ErrorTree, GenericErrorTree, StackContext},
+    tag::complete::tag,
+    ParserExt,
+};
+
+use super::{grid::Grid, ParseError};
+
+pub type Res<'a, T> = IResult<&'a str, T, ErrorTree<&'a str>>;
+
+/// Runs `parser` on the whole of `input`, allowing surrounding whitespace.
+pub fn finish<'a, O>(
+    day: u8,
+    input: &'a str,
+    parser: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> Result<O, ParseError> {
+    match all_consuming(delimited(multispace0, parser, multispace0))(input) {
+        Ok((_, res)) => Ok(res),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            let (location, message) = describe(&e);
+            Err(ParseError::at(day, input, &location[..0], message))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("only complete parsers are used"),
+    }
+}
+
+/// The location and message of the error that got furthest into the input.
+fn describe<'a>(error: &ErrorTree<&'a str>) -> (&'a str, String) {
+    match error {
+        GenericErrorTree::Base { location, kind } => {
+            let message = match kind {
+                BaseErrorKind::Expected(expectation) => format!("expected {expectation}"),
+                BaseErrorKind::Kind(kind) => format!("unexpected input ({kind:?})"),
+                BaseErrorKind::External(e) => e.to_string(),
+            };
+            (location, message)
+        }
+        GenericErrorTree::Stack { base, contexts } => {
+            let (location, message) = describe(base);
+            let context = contexts.iter().find_map(|(_, context)| match context {
+                StackContext::Context(context) => Some(context),
+                StackContext::Kind(_) => None,
+            });
+            match (context, base.as_ref()) {
+                (
+                    _,
+                    GenericErrorTree::Base {
+                        kind: BaseErrorKind::External(_),
+                        ..
+                    },
+                ) => (location, message),
+                (Some(context), _) => (location, format!("expected {context}")),
+                (None, _) => (location, message),
+            }
+        }
+        GenericErrorTree::Alt(alternatives) => alternatives
+            .iter()
+            .map(describe)
+            .min_by_key(|(location, _)| location.len())
+            .expect("alternatives are never empty"),
+    }
+}
+
+/// An error at `location` that no alternative can recover from.
+pub fn fail(location: &str, message: impl Into<String>) -> nom::Err<ErrorTree<&str>> {
+    nom::Err::Failure(ErrorTree::from_external_error(
+        location,
+        nom::error::ErrorKind::Verify,
+        message.into(),
+    ))
+}
+
+/// A single number, signed or not depending on `T`. The whole token up to the
+/// next space or punctuation is taken, so `3x` is an invalid number rather
+/// than a 3 followed by garbage.
+pub fn number<'a, T: FromStr>(input: &'a str) -> Res<'a, T> {
+    nom::bytes::complete::take_while1(|c: char| c.is_alphanumeric() || c == '-' || c == '+')
+        .map_res_cut(|token: &str| {
+            token
+                .parse::<T>()
+                .map_err(|_| format!("invalid number '{token}'"))
+        })
+        .parse(input)
+}
+
+/// Numbers separated by spaces or tabs, on a single line.
+pub fn numbers<'a, T: FromStr>(input: &'a str) -> Res<'a, Vec<T>> {
+    separated_list1(space1, number).parse(input)
+}
+
+/// `<name>:`, as in `Time:`, followed by optional spaces.
+pub fn header<'a>(name: &'static str) -> impl FnMut(&'a str) -> Res<'a, &'a str> {
+    move |input| terminated(terminated(tag(name), char(':')), space0).parse(input)
+}
+
+/// `<name> <N>:`, as in `Game 12:`, followed by optional spaces.
+pub fn label<'a>(name: &'static str) -> impl FnMut(&'a str) -> Res<'a, u32> {
+    move |input| {
+        delimited(
+            tag(name).terminated(space1),
+            number,
+            char(':').terminated(space0),
+        )
+        .parse(input)
+    }
+}
+
+/// `key = (a, b)` with alphanumeric names.
+pub fn key_pair(input: &str) -> Res<'_, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        delimited(space0, char('='), space0),
+        delimited(
+            char('('),
+            separated_pair(alphanumeric1, char(',').terminated(space0), alphanumeric1),
+            char(')'),
+        ),
+    )
+    .parse(input)
+}
+
+/// A line holding exactly what `item` parses, up to its line break.
+fn trimmed_line<'a, O>(
+    item: &mut impl Parser<&'a str, O, ErrorTree<&'a str>>,
+    input: &'a str,
+) -> Res<'a, O> {
+    let (input, _) = space0(input)?;
+    let (input, res) = item.parse(input)?;
+    let (input, _) = space0(input)?;
+    if let Some(rest) = input.strip_prefix("\r\n").or(input.strip_prefix('\n')) {
+        Ok((rest, res))
+    } else if input.is_empty() {
+        Ok((input, res))
+    } else {
+        let token = input.split_whitespace().next().unwrap_or(input);
+        Err(nom::Err::Error(ErrorTree::from_external_error(
+            input,
+            nom::error::ErrorKind::Eof,
+            format!("unexpected '{token}'"),
+        )))
+    }
+}
+
+fn cut<E>(err: nom::Err<E>) -> nom::Err<E> {
+    match err {
+        nom::Err::Error(e) => nom::Err::Failure(e),
+        e => e,
+    }
+}
+
+/// A single trimmed line.
+pub fn line<'a, O>(
+    mut item: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> Res<'a, O> {
+    move |input| trimmed_line(&mut item, input)
+}
+
+/// Every remaining non-blank line.
+pub fn lines<'a, O>(
+    mut item: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> Res<'a, Vec<O>> {
+    move |mut input| {
+        let mut res = Vec::new();
+        loop {
+            let (rest, _) = multispace0(input)?;
+            if rest.is_empty() {
+                return Ok((rest, res));
+            }
+            let (rest, item) = trimmed_line(&mut item, rest).map_err(cut)?;
+            res.push(item);
+            input = rest;
+        }
+    }
+}
+
+/// Consecutive non-blank lines, up to the next blank line.
+pub fn block<'a, O>(
+    mut item: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> Res<'a, Vec<O>> {
+    move |mut input| {
+        let mut res = Vec::new();
+        loop {
+            let (rest, _) = space0(input)?;
+            if rest.is_empty() || rest.starts_with('\n') || rest.starts_with("\r\n") {
+                return Ok((input, res));
+            }
+            let (rest, item) = trimmed_line(&mut item, input).map_err(cut)?;
+            res.push(item);
+            input = rest;
+        }
+    }
+}
+
+/// Blank line separated sections, each a header line followed by a block of
+/// items.
+pub fn sections<'a, H, O>(
+    mut header: impl Parser<&'a str, H, ErrorTree<&'a str>>,
+    item: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> Res<'a, Vec<(H, Vec<O>)>> {
+    let mut block = block(item);
+    move |mut input| {
+        let mut res = Vec::new();
+        loop {
+            let (rest, _) = multispace0(input)?;
+            if rest.is_empty() {
+                return Ok((rest, res));
+            }
+            let (rest, header) = trimmed_line(&mut header, rest).map_err(cut)?;
+            let (rest, items) = block(rest)?;
+            res.push((header, items));
+            input = rest;
+        }
+    }
+}
+
+/// One row of cells, trimmed.
+fn row<'a, T>(input: &'a str) -> Res<'a, Vec<T>>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    let (_, line) = not_line_ending(input)?;
+    let line = line.trim_end();
+    let cells = line
+        .char_indices()
+        .map(|(idx, c)| T::try_from(c).map_err(|e| fail(&line[idx..], e.to_string())))
+        .collect::<Result<_, _>>()?;
+    Ok((&input[line.len()..], cells))
+}
+
+/// A rectangular grid of characters, one row per non-blank line.
+pub fn grid<'a, T>(input: &'a str) -> Res<'a, Grid<T>>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    let (rest, rows) = lines(row::<T>.with_recognized())(input)?;
+    let Some((_, first)) = rows.first() else {
+        return Err(fail(rest, "expected at least one row"));
+    };
+
+    let width = first.len();
+    let mut cells = Vec::with_capacity(width * rows.len());
+    for (source, row) in rows {
+        if row.len() != width {
+            return Err(fail(source, format!("expected a row of {width} cells")));
+        }
+        cells.extend(row);
+    }
+    Ok((rest, Grid::new(width, cells)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_lists() {
+        assert_eq!(finish(1, " 1 -2\t3 ", numbers::<i64>), Ok(vec![1, -2, 3]));
+
+        let err = finish(1, "1 -2", numbers::<u32>).unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (3, "invalid number '-2'")
+        );
+    }
+
+    #[test]
+    fn labels_and_pairs() {
+        let input = "Card  12: AAA = (BBB, CCC)";
+        let (_, (id, pair)) = label("Card").and(key_pair).parse(input).unwrap();
+        assert_eq!((id, pair), (12, ("AAA", ("BBB", "CCC"))));
+
+        let err = finish(1, "Card 1 AAA", label("Card")).unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (7, "expected ':'"));
+    }
+
+    #[test]
+    fn line_based() {
+        let input = "  seeds: 1 2\n\n  a:\n  1\n  2\n\n\n  b:\n  3\n";
+        let parser = line(header("seeds").precedes(numbers::<u32>))
+            .and(sections(header("a").or(header("b")), number::<u32>));
+        assert_eq!(
+            finish(1, input, parser),
+            Ok((vec![1, 2], vec![("a", vec![1, 2]), ("b", vec![3])]))
+        );
+
+        let err = finish(1, "1 2\n3 4 x\n", lines(numbers::<u32>)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    #[test]
+    fn trailing_input() {
+        let err = finish(1, "1 2 | 3", lines(numbers::<u32>)).unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (5, "unexpected '|'"));
+    }
+}
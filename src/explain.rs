@@ -0,0 +1,56 @@
+//! Human-readable derivations of answers, for days that can tell how they
+//! got there.
+
+use std::fmt::{self, Display};
+
+/// One step towards an answer, usually one line or item of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainStep {
+    /// What the step is about, like `line 3` or `Card 12`.
+    pub subject: String,
+    /// What came of it. More than one line for the days that show a
+    /// drawing, like the difference pyramids of day 9.
+    pub lines: Vec<String>,
+}
+
+impl ExplainStep {
+    pub fn new(subject: impl Into<String>, line: impl Into<String>) -> Self {
+        ExplainStep {
+            subject: subject.into(),
+            lines: vec![line.into()],
+        }
+    }
+}
+
+impl Display for ExplainStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lines.as_slice() {
+            [line] => write!(f, "{}: {line}", self.subject),
+            lines => {
+                write!(f, "{}:", self.subject)?;
+                for line in lines {
+                    write!(f, "\n    {line}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ExplainStep::new("line 1", "first 1, last 2").to_string(),
+            "line 1: first 1, last 2"
+        );
+        let step = ExplainStep {
+            subject: "sequence 1".to_string(),
+            lines: vec!["1 2".to_string(), " 1".to_string()],
+        };
+        assert_eq!(step.to_string(), "sequence 1:\n    1 2\n     1");
+    }
+}
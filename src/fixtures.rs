@@ -0,0 +1,200 @@
+//! Example inputs from the puzzle descriptions, kept as files with the
+//! answers they should give.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context};
+use toml::{Table, Value};
+
+use crate::{
+    answers::answer,
+    solution::{Params, Part},
+};
+
+/// Holds the examples as `<year>/day<N>/<name>.txt`, each next to a
+/// `<name>.toml` with its answers.
+pub const DEFAULT_DIR: &str = "tests/fixtures";
+
+/// One answer an example is expected to have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub part: Part,
+    pub params: Params,
+    pub expected: String,
+}
+
+/// An example input from a puzzle description with the answers it has. Its
+/// sidecar lists them the way answer files do, and repeats them in a
+/// `[[params]]` table per set of parameters they take:
+///
+/// ```toml
+/// part1 = 374
+///
+/// [[params]]
+/// factor = 10
+/// part2 = 1030
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub input: PathBuf,
+    pub checks: Vec<Check>,
+}
+
+/// The checks of a sidecar. Keys besides the parts are only allowed in
+/// `[[params]]` tables.
+pub fn parse_checks(content: &str) -> anyhow::Result<Vec<Check>> {
+    let mut res = Vec::new();
+    let mut add = |table: Table, in_params: bool| -> anyhow::Result<()> {
+        let mut answers = Vec::new();
+        let mut given = Params::new();
+        for (key, value) in table {
+            match (key.as_str(), &value) {
+                ("part1", value) => answers.push((Part::One, answer(value)?)),
+                ("part2", value) => answers.push((Part::Two, answer(value)?)),
+                (_, Value::Integer(n)) if in_params => {
+                    given.insert(key, *n);
+                }
+                (k, Value::Integer(_)) => {
+                    bail!("unexpected key `{k}`, parameters go in `[[params]]`")
+                }
+                (k, v) => bail!("expected a number as parameter `{k}`, got {v}"),
+            }
+        }
+        if in_params && answers.is_empty() {
+            bail!("expected an answer for the parameters {given:?}");
+        }
+        res.extend(answers.into_iter().map(|(part, expected)| Check {
+            part,
+            params: given.clone(),
+            expected,
+        }));
+        Ok(())
+    };
+
+    let mut table = content.parse::<Table>()?;
+    let with_params = match table.remove("params") {
+        Some(Value::Array(tables)) => tables,
+        Some(_) => bail!("expected `params` to be an array of tables"),
+        None => Vec::new(),
+    };
+    add(table, false)?;
+    for params in with_params {
+        let Value::Table(params) = params else {
+            bail!("expected `params` to be an array of tables");
+        };
+        add(params, true)?;
+    }
+
+    if res.is_empty() {
+        bail!("expected at least one answer");
+    }
+    Ok(res)
+}
+
+/// Every example in `dir`, by year, day and name.
+pub fn discover(dir: impl AsRef<Path>) -> anyhow::Result<Vec<Fixture>> {
+    let number = |path: &Path, prefix: &str| -> Option<u16> {
+        path.file_name()?
+            .to_str()?
+            .strip_prefix(prefix)?
+            .parse()
+            .ok()
+    };
+    let entries = |dir: &Path| -> anyhow::Result<Vec<PathBuf>> {
+        let mut res = fs::read_dir(dir)
+            .with_context(|| format!("failed to read {}", dir.display()))?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        res.sort();
+        Ok(res)
+    };
+
+    let mut res = Vec::new();
+    for year_dir in entries(dir.as_ref())? {
+        let Some(year) = number(&year_dir, "").filter(|_| year_dir.is_dir()) else {
+            continue;
+        };
+        for day_dir in entries(&year_dir)? {
+            let day = number(&day_dir, "day")
+                .and_then(|day| u8::try_from(day).ok())
+                .filter(|_| day_dir.is_dir())
+                .ok_or_else(|| anyhow!("expected a `dayN` directory, got {}", day_dir.display()))?;
+            for path in entries(&day_dir)? {
+                match path.extension().and_then(|ext| ext.to_str()) {
+                    Some("txt") => {}
+                    Some("toml") if path.with_extension("txt").exists() => continue,
+                    _ => bail!("{} isn't next to an example input", path.display()),
+                }
+                let sidecar = path.with_extension("toml");
+                let content = fs::read_to_string(&sidecar)
+                    .with_context(|| format!("failed to read {}", sidecar.display()))?;
+                let checks = parse_checks(&content)
+                    .with_context(|| format!("failed to parse {}", sidecar.display()))?;
+                res.push(Fixture {
+                    year,
+                    day,
+                    name: path
+                        .file_stem()
+                        .and_then(|stem| stem.to_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    input: path,
+                    checks,
+                });
+            }
+        }
+    }
+    res.sort_by(|a, b| (a.year, a.day, &a.name).cmp(&(b.year, b.day, &b.name)));
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sidecar() {
+        let checks = parse_checks(
+            "part1 = 374\n\n[[params]]\nfactor = 10\npart2 = 1030\n\n\
+             [[params]]\nfactor = 100\npart2 = \"8410\"\n",
+        )
+        .unwrap();
+
+        let factor = |n| Params::from([("factor".to_string(), n)]);
+        assert_eq!(
+            checks,
+            [
+                Check {
+                    part: Part::One,
+                    params: Params::new(),
+                    expected: "374".to_string()
+                },
+                Check {
+                    part: Part::Two,
+                    params: factor(10),
+                    expected: "1030".to_string()
+                },
+                Check {
+                    part: Part::Two,
+                    params: factor(100),
+                    expected: "8410".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reject_bad_sidecars() {
+        assert!(parse_checks("").is_err());
+        assert!(parse_checks("factor = 10\npart2 = 1030").is_err());
+        assert!(parse_checks("part1 = 1\n[[params]]\nfactor = 10").is_err());
+        assert!(parse_checks("[[params]]\nfactor = \"ten\"\npart2 = 1").is_err());
+        assert!(parse_checks("part3 = 1").is_err());
+    }
+}
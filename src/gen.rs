@@ -0,0 +1,59 @@
+//! Seeded generators for synthetic puzzle inputs of any size, to find out how
+//! the solutions scale without needing more real inputs.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+pub mod y2023;
+
+/// ChaCha output is stable across versions, so a seed keeps producing the
+/// same input.
+pub type Rng = ChaCha8Rng;
+
+/// A generator for the inputs of one day.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// What `size` controls for this day.
+    pub size: &'static str,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::seed_from_u64(seed), size)
+    }
+}
+
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2023,
+        day: 5,
+        size: "the number of ranges per map, and up to size * 10000 seeds per seed range",
+        generate: y2023::day5,
+    },
+    Generator {
+        year: 2023,
+        day: 8,
+        size: "roughly the number of nodes",
+        generate: y2023::day8,
+    },
+    Generator {
+        year: 2023,
+        day: 10,
+        size: "the width and height of the map",
+        generate: y2023::day10,
+    },
+    Generator {
+        year: 2023,
+        day: 11,
+        size: "the number of galaxies",
+        generate: y2023::day11,
+    },
+];
+
+pub fn get(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.year == year && generator.day == day)
+}
@@ -0,0 +1,353 @@
+use std::{collections::HashSet, fmt::Write};
+
+use rand::{
+    seq::{IndexedRandom, SliceRandom},
+    Rng as _,
+};
+
+use super::Rng;
+
+/// Every seed and location of day 5 is below this.
+const SPAN: u64 = u32::MAX as u64;
+
+/// Ten seed ranges of up to `size * 10000` seeds, or a tenth of all seeds
+/// for big sizes.
+fn seed_ranges(rng: &mut Rng, size: usize) -> Vec<(u64, u64)> {
+    let longest = (size.max(1) as u64).saturating_mul(10_000).min(SPAN / 10);
+    (0..10)
+        .map(|_| {
+            let len = rng.random_range(1..=longest);
+            (rng.random_range(0..SPAN - len), len)
+        })
+        .collect()
+}
+
+/// Ten seed ranges followed by seven maps, each a shuffled bijection of
+/// `0..u32::MAX` split into `size` ranges.
+pub fn day5(rng: &mut Rng, size: usize) -> String {
+    const CHAIN: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    let size = size.max(1);
+
+    let mut res = String::from("seeds:");
+    for (start, len) in seed_ranges(rng, size) {
+        write!(res, " {start} {len}").unwrap();
+    }
+    res.push('\n');
+
+    for names in CHAIN.windows(2) {
+        let mut cuts: Vec<u64> = (1..size).map(|_| rng.random_range(1..SPAN)).collect();
+        cuts.extend([0, SPAN]);
+        cuts.sort_unstable();
+        cuts.dedup();
+        let mut ranges: Vec<(u64, u64, u64)> =
+            cuts.windows(2).map(|w| (0, w[0], w[1] - w[0])).collect();
+
+        // Lay the ranges out in a shuffled order on the destination side.
+        ranges.shuffle(rng);
+        let mut destination = 0;
+        for range in ranges.iter_mut() {
+            range.0 = destination;
+            destination += range.2;
+        }
+        ranges.shuffle(rng);
+
+        write!(res, "\n{}-to-{} map:\n", names[0], names[1]).unwrap();
+        for (destination, source, len) in ranges {
+            writeln!(res, "{destination} {source} {len}").unwrap();
+        }
+    }
+    res
+}
+
+/// A name for node `idx` made of the letters B to Y, so it never looks like
+/// a start or an end.
+fn node_name(mut idx: usize, width: usize) -> String {
+    let mut res = vec![b'B'; width];
+    for c in res.iter_mut().rev() {
+        *c = b'B' + (idx % 24) as u8;
+        idx /= 24;
+    }
+    String::from_utf8(res).unwrap()
+}
+
+/// Six ghosts walking separate cycles, each a distinct prime multiple of the
+/// length of the directions, starting with the walk from `AAA` to `ZZZ`.
+pub fn day8(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [usize; 16] = [
+        29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    ];
+    const GHOSTS: usize = 6;
+
+    let directions: Vec<usize> = (0..(size / (GHOSTS * 60)).max(1))
+        .map(|_| rng.random_range(0..2))
+        .collect();
+    let cycles: Vec<usize> = PRIMES
+        .choose_multiple(rng, GHOSTS)
+        .map(|prime| prime * directions.len())
+        .collect();
+    let mut width = 3;
+    while 24usize.pow(width as u32) < cycles.iter().sum() {
+        width += 1;
+    }
+
+    let mut next = 0;
+    let mut nodes = Vec::new();
+    for (ghost, &cycle) in cycles.iter().enumerate() {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            let prefix = node_name(ghost, width - 1);
+            (format!("{prefix}A"), format!("{prefix}Z"))
+        };
+        let mut names = vec![start];
+        names.extend((1..cycle).map(|offset| node_name(next + offset, width)));
+        names.push(end);
+        next += cycle;
+
+        // Node `step` is only ever left in the direction of that step, the
+        // other child is a dead end nobody takes.
+        for step in 0..=cycle {
+            let target = if step == cycle { 1 } else { step + 1 };
+            let decoy = rng.random_range(1..cycle.max(2));
+            let mut children = [&names[target], &names[decoy]];
+            if directions[step % directions.len()] == 1 {
+                children.reverse();
+            }
+            nodes.push(format!(
+                "{} = ({}, {})",
+                names[step], children[0], children[1]
+            ));
+        }
+    }
+    nodes.shuffle(rng);
+
+    let directions: String = directions
+        .iter()
+        .map(|&d| if d == 0 { 'L' } else { 'R' })
+        .collect();
+    format!("{directions}\n\n{}\n", nodes.join("\n"))
+}
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+fn pipe(connections: u8) -> char {
+    match connections {
+        c if c == NORTH | SOUTH => '|',
+        c if c == EAST | WEST => '-',
+        c if c == NORTH | EAST => 'L',
+        c if c == NORTH | WEST => 'J',
+        c if c == SOUTH | WEST => '7',
+        c if c == SOUTH | EAST => 'F',
+        _ => '.',
+    }
+}
+
+/// One loop around a random tree of 3x3 blocks, covering about 60% of the
+/// map. The rest is ground and pipes that don't belong to the loop.
+///
+/// A tree of `n` blocks makes a loop of `6n + 2` tiles enclosing `3n - 2`,
+/// so the answer to part 1 is always 3 more than the one to part 2.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    let blocks = (size / 3).max(1);
+    let side = blocks * 3;
+
+    // Grow a random tree of blocks, every block being its own little loop
+    // until it gets merged with its parent.
+    let mut in_tree = vec![false; blocks * blocks];
+    let root = rng.random_range(0..blocks * blocks);
+    in_tree[root] = true;
+    let mut edges = Vec::new();
+    let mut frontier = Vec::new();
+    let push_frontier = |frontier: &mut Vec<(usize, usize)>, block: usize| {
+        let (row, col) = (block / blocks, block % blocks);
+        if row > 0 {
+            frontier.push((block, block - blocks));
+        }
+        if row + 1 < blocks {
+            frontier.push((block, block + blocks));
+        }
+        if col > 0 {
+            frontier.push((block, block - 1));
+        }
+        if col + 1 < blocks {
+            frontier.push((block, block + 1));
+        }
+    };
+    push_frontier(&mut frontier, root);
+    let target = (blocks * blocks * 3).div_ceil(5);
+    let mut count = 1;
+    while count < target && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.random_range(0..frontier.len()));
+        if !in_tree[to] {
+            in_tree[to] = true;
+            count += 1;
+            edges.push((from.min(to), from.max(to)));
+            push_frontier(&mut frontier, to);
+        }
+    }
+
+    let mut tiles = vec![0u8; side * side];
+    let tile = |row: usize, col: usize| row * side + col;
+    for block in (0..blocks * blocks).filter(|&block| in_tree[block]) {
+        let (row, col) = (block / blocks * 3, block % blocks * 3);
+        tiles[tile(row, col)] = SOUTH | EAST;
+        tiles[tile(row, col + 1)] = EAST | WEST;
+        tiles[tile(row, col + 2)] = SOUTH | WEST;
+        tiles[tile(row + 1, col)] = NORTH | SOUTH;
+        tiles[tile(row + 1, col + 2)] = NORTH | SOUTH;
+        tiles[tile(row + 2, col)] = NORTH | EAST;
+        tiles[tile(row + 2, col + 1)] = EAST | WEST;
+        tiles[tile(row + 2, col + 2)] = NORTH | WEST;
+    }
+    // Joining two blocks opens the walls between them and connects their
+    // corners, so the insides of both become one.
+    let mut reroute = |row: usize, col: usize, from: u8, to: u8| {
+        let tile = &mut tiles[tile(row, col)];
+        *tile = if from == to { 0 } else { *tile & !from | to };
+    };
+    for (a, b) in edges {
+        let (row, col) = (a / blocks * 3, a % blocks * 3);
+        if b == a + 1 {
+            reroute(row, col + 2, SOUTH, EAST);
+            reroute(row + 1, col + 2, 0, 0);
+            reroute(row + 2, col + 2, NORTH, EAST);
+            reroute(row, col + 3, SOUTH, WEST);
+            reroute(row + 1, col + 3, 0, 0);
+            reroute(row + 2, col + 3, NORTH, WEST);
+        } else {
+            reroute(row + 2, col, EAST, SOUTH);
+            reroute(row + 2, col + 1, 0, 0);
+            reroute(row + 2, col + 2, WEST, SOUTH);
+            reroute(row + 3, col, EAST, NORTH);
+            reroute(row + 3, col + 1, 0, 0);
+            reroute(row + 3, col + 2, WEST, NORTH);
+        }
+    }
+
+    let on_loop: Vec<usize> = (0..tiles.len()).filter(|&idx| tiles[idx] != 0).collect();
+    let mut map: Vec<char> = tiles.iter().map(|&tile| pipe(tile)).collect();
+    for (idx, c) in map.iter_mut().enumerate() {
+        if tiles[idx] == 0 && rng.random_bool(0.5) {
+            *c = *['|', '-', 'L', 'J', '7', 'F'].choose(rng).unwrap();
+        }
+    }
+
+    // Only the two loop tiles next to the start may connect to it.
+    let origin = *on_loop.choose(rng).unwrap();
+    map[origin] = 'S';
+    let (row, col) = (origin / side, origin % side);
+    let neighbours = [
+        (row.checked_sub(1).map(|row| (row, col)), ['|', '7', 'F']),
+        ((row + 1 < side).then_some((row + 1, col)), ['|', 'L', 'J']),
+        (col.checked_sub(1).map(|col| (row, col)), ['-', 'L', 'F']),
+        ((col + 1 < side).then_some((row, col + 1)), ['-', 'J', '7']),
+    ];
+    for (neighbour, towards_origin) in neighbours {
+        if let Some((row, col)) = neighbour {
+            let idx = tile(row, col);
+            if tiles[idx] == 0 && towards_origin.contains(&map[idx]) {
+                map[idx] = '.';
+            }
+        }
+    }
+
+    let mut res = String::with_capacity(side * (side + 1));
+    for row in map.chunks(side) {
+        res.extend(row);
+        res.push('\n');
+    }
+    res
+}
+
+/// `size` galaxies on a square map with about a tenth of the rows and
+/// columns left empty.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let side = ((size * 5) as f64).sqrt().ceil() as usize + 1;
+    let mut pick_used = || {
+        let used: Vec<usize> = (0..side).filter(|_| !rng.random_bool(0.1)).collect();
+        if used.is_empty() {
+            vec![0]
+        } else {
+            used
+        }
+    };
+    let (rows, cols) = (pick_used(), pick_used());
+    let size = size.min(rows.len() * cols.len());
+
+    let mut galaxies = HashSet::new();
+    while galaxies.len() < size {
+        galaxies.insert((*rows.choose(rng).unwrap(), *cols.choose(rng).unwrap()));
+    }
+
+    let mut res = String::with_capacity(side * (side + 1));
+    for row in 0..side {
+        res.extend((0..side).map(|col| {
+            if galaxies.contains(&(row, col)) {
+                '#'
+            } else {
+                '.'
+            }
+        }));
+        res.push('\n');
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        gen,
+        solution::Solution,
+        y2023::{day10::Day10, day11::Day11, day5::Day5, day8::Day8},
+    };
+
+    #[test]
+    fn seeded() {
+        for generator in gen::GENERATORS {
+            assert_eq!(generator.generate(7, 50), generator.generate(7, 50));
+            assert_ne!(generator.generate(7, 50), generator.generate(8, 50));
+        }
+    }
+
+    #[test]
+    fn long_seed_ranges() {
+        let mut rng = Rng::seed_from_u64(2);
+        for size in [429_497, 1_000_000, usize::MAX] {
+            for (start, len) in seed_ranges(&mut rng, size) {
+                assert!(start + len <= SPAN);
+            }
+        }
+    }
+
+    #[test]
+    fn valid_inputs() {
+        let input = |day| gen::get(2023, day).unwrap().generate(1, 40);
+
+        Day5::solve_part1(&input(5)).unwrap();
+        let map = Day10::parse(&input(10)).unwrap();
+        assert_eq!(Day10::part1(&map).unwrap(), Day10::part2(&map).unwrap() + 3);
+        Day11::solve_part2(&input(11)).unwrap();
+        for size in [1, 400, 1000] {
+            let input = gen::get(2023, 8).unwrap().generate(3, size);
+            let map = Day8::parse(&input).unwrap();
+            let walk = Day8::part1(&map).unwrap();
+            assert_eq!(Day8::part2(&map).unwrap() % walk, 0);
+        }
+    }
+}
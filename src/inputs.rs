@@ -0,0 +1,100 @@
+//! The real puzzle inputs, downloaded once and kept under `input/`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+use crate::client::Client;
+
+pub fn path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{year}/day{day}.txt"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there, nothing was requested.
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `day` of `year` to `path`, unless a non-empty file
+/// is already there. Empty files are placeholders and get replaced.
+pub fn fetch(client: &Client, year: u16, day: u8, path: &Path) -> anyhow::Result<Fetched> {
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let response = client.get(&format!("/{year}/day/{day}/input"))?;
+    match response.status {
+        200 if response.body.is_empty() => bail!("received an empty input for day {day}"),
+        200 => {}
+        400 | 500 => bail!(
+            "the input of day {day} was refused ({}), is the session cookie still valid?",
+            response.status
+        ),
+        404 => bail!("the input of day {day} is not available (yet)"),
+        status => bail!(
+            "failed to download the input of day {day} ({status}): {}",
+            response.body.lines().next().unwrap_or_default()
+        ),
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    // Write to a temporary file first so an interrupted download never leaves
+    // a truncated input behind, which would be picked up as cached next time.
+    let tmp = path.with_extension("txt.part");
+    fs::write(&tmp, &response.body)
+        .and_then(|()| fs::rename(&tmp, path))
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::client::mock;
+
+    fn client(url: String) -> Client {
+        Client::new("secret")
+            .with_base_url(url)
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn downloads_once() {
+        let dir = std::env::temp_dir().join(format!("aoc23-inputs-{}", std::process::id()));
+        let path = dir.join("2023/day3.txt");
+        let _ = fs::remove_dir_all(&dir);
+
+        let (url, server) = mock::serve(vec![(200, "467..114..\n".to_string())]);
+        let client = client(url);
+        assert_eq!(fetch(&client, 2023, 3, &path).unwrap(), Fetched::Downloaded);
+        // The mock only answers once, a second request would fail.
+        assert_eq!(fetch(&client, 2023, 3, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "467..114..\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/3/input "));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn not_unlocked() {
+        let dir = std::env::temp_dir().join(format!("aoc23-locked-{}", std::process::id()));
+        let path = dir.join("day25.txt");
+
+        let (url, server) = mock::serve(vec![(404, "Not Found".to_string())]);
+        let err = fetch(&client(url), 2023, 25, &path).unwrap_err();
+        assert!(err.to_string().contains("not available"));
+        assert!(!path.exists());
+        server.join().unwrap();
+    }
+}
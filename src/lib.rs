@@ -1,16 +1,59 @@
-use aoc_runner_derive::aoc_lib;
-
+pub mod animate;
+pub mod answers;
+pub mod client;
 pub mod common;
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+pub mod explain;
+pub mod fixtures;
+pub mod gen;
+pub mod inputs;
+mod profile;
+pub mod progress;
+pub mod render;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod watch;
+pub mod y2023;
+
+use solution::Day;
+
+/// The days of every implemented year, oldest year first.
+pub static YEARS: &[&[Day]] = &[y2023::DAYS];
+
+/// Every implemented day of every year, in calendar order.
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+pub fn get_day(year: u16, day: u8) -> Option<&'static Day> {
+    days().find(|d| d.year == year && d.day == day)
+}
+
+/// The year commands default to.
+pub fn latest_year() -> u16 {
+    days()
+        .map(|d| d.year)
+        .max()
+        .expect("there is at least one day")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-aoc_lib! { year = 2023 }
+    #[test]
+    fn registry_is_ordered_and_unique() {
+        let days: Vec<_> = days().collect();
+        assert!(days
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+        assert_eq!(
+            get_day(2023, 5).unwrap().title,
+            "If You Give A Seed A Fertilizer"
+        );
+        assert!(get_day(2023, 26).is_none());
+        assert!(get_day(2022, 5).is_none());
+    }
+}
@@ -0,0 +1,709 @@
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::{self, ExitCode, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context};
+use aoc23::{
+    animate,
+    answers::{self, Answers},
+    client::Client,
+    common::human_readable_duration,
+    gen,
+    inputs::{self, Fetched},
+    progress,
+    render::{self, Palette, Rgb, Shade},
+    report::Report,
+    runner::{self, Outcome, Summary},
+    scaffold,
+    solution::{Day, Part},
+    submit::{self, Verdict},
+    watch::{self, Snapshot},
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use tracing_subscriber::EnvFilter;
+
+const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Parser)]
+#[command(name = "aoc23", about = "Advent of Code solutions")]
+struct Cli {
+    /// The puzzle year, defaults to the latest one. `all` and `list` cover
+    /// every year unless given
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+    /// Log what the solutions are doing to stderr, `-vv` for even more.
+    /// Without it the filter in `AOC_LOG` is used, e.g. `aoc23::y2023::day11=trace`
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Answers only for `run`, a table for `all`
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day against a file or stdin
+    Run {
+        #[arg(short, long)]
+        day: u8,
+        /// Run only this part, both parts otherwise
+        #[arg(short, long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Input file, `-` for stdin. Defaults to `input/<year>/day<N>.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Show how each answer comes about, for the days that can tell
+        #[arg(long, conflicts_with = "format")]
+        explain: bool,
+    },
+    /// Run every day and part against its real input and print a timing table
+    All {
+        /// Run all days at once, which makes the individual timings less reliable
+        #[arg(long)]
+        parallel: bool,
+        /// Also run the parts marked as slow in the answers
+        #[arg(long)]
+        slow: bool,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// List all available days and parts
+    List,
+    /// Download puzzle inputs that aren't there yet, using the session cookie
+    /// in `AOC_SESSION`
+    Fetch {
+        /// Only this day, every implemented day otherwise
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Use another server than adventofcode.com, also read from `AOC_BASE_URL`
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Submit an answer, using the session cookie in `AOC_SESSION`. Answers
+    /// already known to be wrong are rejected without asking
+    Submit {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long, value_parser = parse_part)]
+        part: Part,
+        /// The answer to submit, defaults to solving the real input
+        #[arg(short, long)]
+        answer: Option<String>,
+        /// Use another server than adventofcode.com, also read from `AOC_BASE_URL`
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Create the skeleton of a new day, register it and add an empty input
+    /// placeholder. Existing days are never overwritten
+    New {
+        #[arg(short, long)]
+        day: u8,
+        /// The puzzle title, defaults to `Day <N>`
+        #[arg(short, long)]
+        title: Option<String>,
+    },
+    /// Generate a synthetic input of any size, for the days that have a
+    /// generator
+    Gen {
+        #[arg(short, long)]
+        day: u8,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big the input gets, see `generators` for what it means per day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// List the days that have a generator and what their size means
+    Generators,
+    /// Run a day under coz for causal profiling, writing `profile.coz`. Needs
+    /// a build with progress points: `cargo build --release --features profile`
+    Profile {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Input file. Defaults to `input/<year>/day<N>.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Draw a grid day as a PPM, PNG or SVG image, picked by the extension
+    /// of `--out`
+    Render {
+        #[arg(short, long)]
+        day: u8,
+        /// Input file, `-` for stdin. Defaults to `input/<year>/day<N>.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long)]
+        out: PathBuf,
+        /// Pixels per cell
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Change a colour, like `--colour marked=#ff0000`. Shades are
+        /// background, plain, marked, highlight, inside and outside
+        #[arg(long, value_parser = parse_colour)]
+        colour: Vec<(Shade, Rgb)>,
+    },
+    /// Replay the walk of day 8 or 10 step by step in the terminal. Space
+    /// pauses, n steps while paused, + and - change the speed and q quits.
+    /// Every frame is printed instead when stdout isn't a terminal
+    Animate {
+        #[arg(short, long)]
+        day: u8,
+        /// Input file, `-` for stdin. Defaults to `input/<year>/day<N>.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Steps per second
+        #[arg(long, default_value_t = 20.0)]
+        speed: f64,
+    },
+    /// Run the tests of a day and its real input again whenever the day, the
+    /// common code or the input changes, showing how the answers changed.
+    /// Needs to be started from the repository root
+    Watch {
+        #[arg(short, long)]
+        day: u8,
+    },
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    value
+        .parse::<u8>()
+        .map_err(|e| e.to_string())
+        .and_then(Part::try_from)
+}
+
+fn parse_colour(value: &str) -> Result<(Shade, Rgb), String> {
+    let (shade, colour) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected `<shade>=#rrggbb`, got '{value}'"))?;
+    Ok((shade.parse()?, colour.parse()?))
+}
+
+fn read_input(year: u16, day: u8, input: Option<PathBuf>) -> anyhow::Result<String> {
+    let path = input.unwrap_or_else(|| inputs::path(year, day));
+    if path.as_os_str() == "-" {
+        let mut res = String::new();
+        io::stdin().read_to_string(&mut res)?;
+        Ok(res)
+    } else {
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))
+    }
+}
+
+fn print_report(summary: &Summary, format: Format) {
+    let report = Report::from(summary);
+    match format {
+        Format::Json => println!("{}", report.to_json()),
+        Format::Csv => print!("{}", report.to_csv()),
+        Format::Text => unreachable!("text output differs per command"),
+    }
+}
+
+fn get_day(year: u16, day: u8) -> anyhow::Result<&'static Day> {
+    aoc23::get_day(year, day).ok_or_else(|| anyhow!("no solution for day {day} of {year}"))
+}
+
+fn run(
+    year: u16,
+    day: u8,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    format: Format,
+    explain: bool,
+) -> anyhow::Result<()> {
+    let solution = get_day(year, day)?;
+    let parts = match part {
+        Some(part) if !solution.parts().contains(&part) => {
+            return Err(anyhow!("day {day} has no part {part}"))
+        }
+        Some(part) => vec![part],
+        None => solution.parts().to_vec(),
+    };
+
+    // Expected answers are only known for the real input.
+    let answers = match input {
+        None => Answers::load(answers::DEFAULT_DIR).unwrap_or_default(),
+        Some(_) => Answers::default(),
+    };
+    let input = read_input(year, day, input)?;
+    let progress = progress::for_stderr();
+    let start = Instant::now();
+    let entries: Vec<_> = parts
+        .into_iter()
+        .map(|part| runner::run_part(solution, part, &input, &answers, &*progress))
+        .collect();
+    let summary = Summary {
+        entries,
+        elapsed: start.elapsed(),
+    };
+
+    if format != Format::Text {
+        print_report(&summary, format);
+    }
+    for entry in summary.entries {
+        match entry.outcome {
+            Outcome::Solved(run) if format == Format::Text => {
+                if explain {
+                    print_explanation(solution, entry.part, &input)?;
+                }
+                println!("{}", run.answer);
+            }
+            Outcome::Failed(e) => return Err(anyhow!(e)),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn print_explanation(solution: &Day, part: Part, input: &str) -> anyhow::Result<()> {
+    let steps = solution.explain(input, part)?;
+    if steps.is_empty() {
+        eprintln!("day {} can't explain part {part} yet", solution.day);
+        return Ok(());
+    }
+    println!("part {part}:");
+    for step in steps {
+        println!("{step}");
+    }
+    Ok(())
+}
+
+fn print_table(summary: &Summary) {
+    let mut rows =
+        vec![["year", "day", "part", "answer", "parse", "solve", "status"].map(String::from)];
+    let (mut parse_total, mut solve_total) = Default::default();
+    for entry in summary.entries.iter() {
+        let (answer, parse, solve) = match &entry.outcome {
+            Outcome::Solved(run) => {
+                parse_total += run.parse_time;
+                solve_total += run.solve_time;
+                (
+                    run.answer.clone(),
+                    human_readable_duration(run.parse_time),
+                    human_readable_duration(run.solve_time),
+                )
+            }
+            _ => Default::default(),
+        };
+        let status = match (&entry.outcome, entry.is_correct()) {
+            (Outcome::Failed(e), _) => format!("failed: {}", e.lines().next().unwrap_or_default()),
+            (Outcome::Skipped(reason), _) => format!("skipped ({reason})"),
+            (_, Some(true)) => "ok".to_string(),
+            (_, Some(false)) => format!("wrong, expected {}", entry.expected.as_ref().unwrap()),
+            (_, None) => "unknown".to_string(),
+        };
+        rows.push([
+            entry.year.to_string(),
+            entry.day.to_string(),
+            entry.part.to_string(),
+            answer,
+            parse,
+            solve,
+            status,
+        ]);
+    }
+    rows.push([
+        "total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        human_readable_duration(parse_total),
+        human_readable_duration(solve_total),
+        format!("{} wall clock", human_readable_duration(summary.elapsed)),
+    ]);
+
+    let mut widths = [0; 7];
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in rows {
+        println!(
+            "{:<w0$}  {:>w1$}  {:>w2$}  {:<w3$}  {:>w4$}  {:>w5$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            row[5],
+            row[6],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+            w5 = widths[5],
+        );
+    }
+}
+
+/// The days of `year`, or of every year.
+fn days(year: Option<u16>) -> impl Iterator<Item = &'static Day> {
+    aoc23::days().filter(move |day| year.is_none_or(|year| day.year == year))
+}
+
+fn run_all(year: Option<u16>, parallel: bool, slow: bool, format: Format) -> anyhow::Result<()> {
+    let answers = Answers::load(answers::DEFAULT_DIR).unwrap_or_default();
+    let summary = runner::run_all(
+        days(year),
+        &answers,
+        &runner::Options { parallel, slow },
+        &*progress::for_stderr(),
+    );
+    match format {
+        Format::Text => print_table(&summary),
+        format => print_report(&summary, format),
+    }
+
+    if summary
+        .entries
+        .iter()
+        .any(|e| e.is_correct() == Some(false))
+    {
+        Err(anyhow!("not all answers are correct"))
+    } else {
+        Ok(())
+    }
+}
+
+fn client(base_url: Option<String>) -> anyhow::Result<Client> {
+    let client = Client::from_env()?;
+    Ok(match base_url {
+        Some(base_url) => client.with_base_url(base_url),
+        None => client,
+    })
+}
+
+fn fetch(year: u16, day: Option<u8>, base_url: Option<String>) -> anyhow::Result<()> {
+    let client = client(base_url)?;
+    let days = match day {
+        Some(day) => vec![day],
+        None => days(Some(year)).map(|day| day.day).collect(),
+    };
+
+    for day in days {
+        let path = inputs::path(year, day);
+        match inputs::fetch(&client, year, day, &path)? {
+            Fetched::Cached => println!("day {day}: {} already exists", path.display()),
+            Fetched::Downloaded => println!("day {day}: downloaded to {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    base_url: Option<String>,
+) -> anyhow::Result<()> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let run = get_day(year, day)?.run(&read_input(year, day, None)?, part)?;
+            println!("day {day} part {part}: {}", run.answer);
+            run.answer
+        }
+    };
+
+    match submit::submit(
+        &client(base_url)?,
+        &submit::history_path(year),
+        year,
+        day,
+        part,
+        &answer,
+    )? {
+        Verdict::Correct => println!("correct"),
+        Verdict::TooHigh => return Err(anyhow!("{answer} is too high")),
+        Verdict::TooLow => return Err(anyhow!("{answer} is too low")),
+        Verdict::Wrong => return Err(anyhow!("{answer} is wrong")),
+        Verdict::Wait(wait) => {
+            return Err(anyhow!(
+                "answered too recently, wait {} before submitting again",
+                human_readable_duration(wait)
+            ))
+        }
+        Verdict::AlreadySolved => println!("day {day} part {part} was already solved"),
+    }
+    Ok(())
+}
+
+fn new(year: u16, day: u8, title: Option<String>) -> anyhow::Result<()> {
+    let title = title.unwrap_or_else(|| format!("Day {day}"));
+    let created = scaffold::create(Path::new("."), year, day, &title)?;
+    println!("created {}", created.source.display());
+    println!("registered in {}", created.module.display());
+    println!(
+        "example goes to {}, its answers next to it",
+        created.example.display()
+    );
+    println!("input goes to {}", created.input.display());
+    Ok(())
+}
+
+fn generate(
+    year: u16,
+    day: u8,
+    seed: u64,
+    size: usize,
+    out: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let generator =
+        gen::get(year, day).ok_or_else(|| anyhow!("no generator for day {day} of {year}"))?;
+    let input = generator.generate(seed, size);
+    match out {
+        Some(path) => {
+            fs::write(&path, input).with_context(|| format!("failed to write {}", path.display()))
+        }
+        None => io::stdout()
+            .write_all(input.as_bytes())
+            .context("failed to write the input"),
+    }
+}
+
+fn render(
+    year: u16,
+    day: u8,
+    input: Option<PathBuf>,
+    out: PathBuf,
+    scale: usize,
+    colours: Vec<(Shade, Rgb)>,
+) -> anyhow::Result<()> {
+    let solution = get_day(year, day)?;
+    if render::Format::from_path(&out).is_none() {
+        bail!(
+            "can't tell the format of {}, use .ppm, .png or .svg",
+            out.display()
+        );
+    }
+    let input = read_input(year, day, input)?;
+    let Some(picture) = solution.render(&input)? else {
+        bail!("day {day} of {year} can't be drawn");
+    };
+
+    let mut palette = Palette::default();
+    for (shade, colour) in colours {
+        palette.set(shade, colour);
+    }
+    picture.write(&out, &palette, scale)
+}
+
+/// How often the watched files are looked at.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+fn watch(year: u16, day: u8) -> anyhow::Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("there is no day {day}, days go from 1 to 25");
+    }
+    let root = Path::new(".");
+    let paths = watch::paths(year, day);
+    if !root.join(&paths[0]).exists() {
+        bail!(
+            "{} doesn't exist, run from the repository root",
+            paths[0].display()
+        );
+    }
+
+    let mut snapshot = Snapshot::take(root, &paths);
+    let mut previous = None;
+    loop {
+        if let Some(report) = rerun(year, day)? {
+            for line in watch::diff(previous.as_ref(), &report) {
+                println!("{line}");
+            }
+            previous = Some(report);
+        }
+
+        eprintln!("waiting for changes, ctrl-c to stop");
+        let changes = loop {
+            thread::sleep(WATCH_INTERVAL);
+            let now = Snapshot::take(root, &paths);
+            let changes = now.changes(&snapshot);
+            if !changes.is_empty() {
+                snapshot = now;
+                break changes;
+            }
+        };
+        for path in changes {
+            eprintln!("changed: {}", path.display());
+        }
+    }
+}
+
+/// Runs the tests and examples of the day, then its real input through a
+/// fresh build. `None` when there are no answers, the errors being on stderr
+/// already.
+fn rerun(year: u16, day: u8) -> anyhow::Result<Option<Report>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let tests = process::Command::new(&cargo)
+        .args(["test", "--quiet", "--lib", &format!("y{year}::day{day}::")])
+        .status()
+        .context("failed to run cargo test")?;
+    if !tests.success() {
+        eprintln!("the tests of day {day} fail");
+    }
+    let examples = process::Command::new(&cargo)
+        .args(["test", "--quiet", "--test", "examples"])
+        .env("AOC_EXAMPLES", format!("{year}/day{day}"))
+        .status()
+        .context("failed to run cargo test")?;
+    if !examples.success() {
+        eprintln!("the examples of day {day} fail");
+    }
+
+    let output = process::Command::new(&cargo)
+        .args(["run", "--release", "--quiet", "--"])
+        .args([
+            "--year",
+            &year.to_string(),
+            "run",
+            "--day",
+            &day.to_string(),
+        ])
+        .args(["--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .context("failed to run cargo run")?;
+    Ok(Report::from_json(&String::from_utf8_lossy(&output.stdout)).ok())
+}
+
+fn profile(year: u16, day: u8, part: Option<Part>, input: Option<PathBuf>) -> anyhow::Result<()> {
+    if !cfg!(feature = "profile") {
+        bail!("built without progress points, rebuild with `--features profile`");
+    }
+    get_day(year, day)?;
+
+    let mut command = process::Command::new("coz");
+    command.args(["run", "---"]).arg(env::current_exe()?).args([
+        "--year",
+        &year.to_string(),
+        "run",
+        "--day",
+        &day.to_string(),
+    ]);
+    if let Some(part) = part {
+        command.args(["--part", &part.to_string()]);
+    }
+    if let Some(input) = input {
+        command.arg("--input").arg(input);
+    }
+    let status = command
+        .status()
+        .context("failed to launch coz, is it installed?")?;
+    if !status.success() {
+        bail!("coz failed with {status}");
+    }
+    eprintln!("results written to profile.coz, view them at https://plasma-umass.org/coz/");
+    Ok(())
+}
+
+/// Logs go to stderr, leaving stdout to the answers.
+fn init_logging(verbose: u8) {
+    let filter = match verbose {
+        0 => EnvFilter::try_from_env(LOG_ENV).unwrap_or_else(|_| EnvFilter::new("warn")),
+        1 => EnvFilter::new("aoc23=debug"),
+        _ => EnvFilter::new("aoc23=trace"),
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    let year = cli.year.unwrap_or_else(aoc23::latest_year);
+    let res = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            explain,
+        } => run(year, day, part, input, format, explain),
+        Command::All {
+            parallel,
+            slow,
+            format,
+        } => run_all(cli.year, parallel, slow, format),
+        Command::List => {
+            for day in days(cli.year) {
+                let parts = day.parts().iter().map(Part::to_string).collect::<Vec<_>>();
+                println!(
+                    "{} day {:>2}: {} (parts {})",
+                    day.year,
+                    day.day,
+                    day.title,
+                    parts.join(", ")
+                );
+            }
+            Ok(())
+        }
+        Command::Fetch { day, base_url } => fetch(year, day, base_url),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        } => submit(year, day, part, answer, base_url),
+        Command::New { day, title } => new(year, day, title),
+        Command::Gen {
+            day,
+            seed,
+            size,
+            out,
+        } => generate(year, day, seed, size, out),
+        Command::Profile { day, part, input } => profile(year, day, part, input),
+        Command::Render {
+            day,
+            input,
+            out,
+            scale,
+            colour,
+        } => render(year, day, input, out, scale, colour),
+        Command::Animate { day, input, speed } => get_day(year, day)
+            .and_then(|solution| animate::play(solution, &read_input(year, day, input)?, speed)),
+        Command::Watch { day } => watch(year, day),
+        Command::Generators => {
+            for generator in gen::GENERATORS {
+                println!(
+                    "{} day {:>2}: size is {}",
+                    generator.year, generator.day, generator.size
+                );
+            }
+            Ok(())
+        }
+    };
+
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
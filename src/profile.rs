@@ -0,0 +1,21 @@
+//! Causal profiling with [coz](https://github.com/plasma-umass/coz), behind
+//! the `profile` feature. Without it the macros expand to nothing.
+
+/// A coz progress point, counted every time it is reached.
+macro_rules! progress {
+    ($name:literal) => {
+        #[cfg(feature = "profile")]
+        coz::progress!($name);
+    };
+}
+
+/// Measures the time until the end of the enclosing block as a coz latency
+/// scope. At most one per block.
+macro_rules! scope {
+    ($name:literal) => {
+        #[cfg(feature = "profile")]
+        coz::scope!($name);
+    };
+}
+
+pub(crate) use {progress, scope};
@@ -0,0 +1,168 @@
+//! Progress reporting for the parts that take long enough to make one wonder
+//! whether they are still going.
+
+use std::{
+    io::IsTerminal,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Receives the progress of a running part, counted in whatever units the
+/// part works through. Shared between threads by parallel parts.
+pub trait Progress: Sync {
+    /// Starts over with `total` units of work ahead.
+    fn start(&self, total: u64);
+
+    /// `units` more units of work are done.
+    fn advance(&self, units: u64);
+
+    /// What is being worked on at the moment.
+    fn message(&self, message: &str);
+
+    fn finish(&self);
+}
+
+/// Reports nothing, which is what tests and benchmarks get.
+pub struct Silent;
+
+impl Progress for Silent {
+    fn start(&self, _total: u64) {}
+
+    fn advance(&self, _units: u64) {}
+
+    fn message(&self, _message: &str) {}
+
+    fn finish(&self) {}
+}
+
+/// A progress bar on stderr.
+pub struct Bar(ProgressBar);
+
+impl Bar {
+    pub fn new() -> Self {
+        let bar = ProgressBar::hidden();
+        bar.set_style(
+            ProgressStyle::with_template("{msg} [{wide_bar}] {percent}% (eta {eta})")
+                .expect("the template is valid"),
+        );
+        Bar(bar)
+    }
+}
+
+impl Default for Bar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Progress for Bar {
+    fn start(&self, total: u64) {
+        self.0
+            .set_draw_target(indicatif::ProgressDrawTarget::stderr());
+        self.0.set_length(total);
+        self.0.set_position(0);
+    }
+
+    fn advance(&self, units: u64) {
+        self.0.inc(units);
+    }
+
+    fn message(&self, message: &str) {
+        self.0.set_message(message.to_string());
+    }
+
+    fn finish(&self) {
+        self.0.finish_and_clear();
+    }
+}
+
+#[derive(Debug, Default)]
+struct LogState {
+    total: u64,
+    done: u64,
+    message: String,
+    last: Option<Instant>,
+}
+
+/// A line on stderr every `interval`, for when nobody looks at a terminal.
+pub struct Log {
+    interval: Duration,
+    state: Mutex<LogState>,
+}
+
+impl Log {
+    pub fn new(interval: Duration) -> Self {
+        Log {
+            interval,
+            state: Mutex::default(),
+        }
+    }
+}
+
+/// `done` out of `total` as a line of log.
+fn log_line(state: &LogState) -> String {
+    let percent = state.done as f64 * 100.0 / state.total.max(1) as f64;
+    let line = format!("{}/{} ({percent:.1}%)", state.done, state.total);
+    if state.message.is_empty() {
+        line
+    } else {
+        format!("{}: {line}", state.message)
+    }
+}
+
+impl Progress for Log {
+    fn start(&self, total: u64) {
+        let mut state = self.state.lock().unwrap();
+        *state = LogState {
+            total,
+            last: Some(Instant::now()),
+            ..Default::default()
+        };
+    }
+
+    fn advance(&self, units: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.done += units;
+        if state
+            .last
+            .is_none_or(|last| last.elapsed() >= self.interval)
+        {
+            state.last = Some(Instant::now());
+            eprintln!("{}", log_line(&state));
+        }
+    }
+
+    fn message(&self, message: &str) {
+        self.state.lock().unwrap().message = message.to_string();
+    }
+
+    fn finish(&self) {}
+}
+
+/// A bar when stderr is a terminal, log lines every 10 seconds otherwise.
+pub fn for_stderr() -> Box<dyn Progress> {
+    if std::io::stderr().is_terminal() {
+        Box::new(Bar::new())
+    } else {
+        Box::new(Log::new(Duration::from_secs(10)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_lines() {
+        let mut state = LogState {
+            total: 8,
+            done: 2,
+            ..Default::default()
+        };
+        assert_eq!(log_line(&state), "2/8 (25.0%)");
+        state.message = "seed range 1/4".to_string();
+        assert_eq!(log_line(&state), "seed range 1/4: 2/8 (25.0%)");
+    }
+}
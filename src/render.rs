@@ -0,0 +1,331 @@
+//! Pictures of the grid days, one square per cell, written as PPM, PNG or
+//! SVG.
+
+use std::{
+    fmt::{self, Display, Write as _},
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context};
+
+use crate::common::grid::Grid;
+
+/// What a cell shows. What exactly is up to the day drawing it, see their
+/// `render` implementations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shade {
+    Background,
+    /// Things that are there but don't count.
+    Plain,
+    /// Things that count towards the answer.
+    Marked,
+    /// The few things that count the most.
+    Highlight,
+    Inside,
+    Outside,
+}
+
+impl Shade {
+    pub const ALL: [Shade; 6] = [
+        Shade::Background,
+        Shade::Plain,
+        Shade::Marked,
+        Shade::Highlight,
+        Shade::Inside,
+        Shade::Outside,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Shade::Background => "background",
+            Shade::Plain => "plain",
+            Shade::Marked => "marked",
+            Shade::Highlight => "highlight",
+            Shade::Inside => "inside",
+            Shade::Outside => "outside",
+        }
+    }
+}
+
+impl FromStr for Shade {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Shade::ALL
+            .into_iter()
+            .find(|shade| shade.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<_> = Shade::ALL.iter().map(|shade| shade.name()).collect();
+                format!("unknown shade '{s}', expected one of {}", names.join(", "))
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub [u8; 3]);
+
+impl FromStr for Rgb {
+    type Err = String;
+
+    /// Parses `#rrggbb`, the `#` being optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel = |idx: usize| {
+            hex.get(idx * 2..idx * 2 + 2)
+                .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+        };
+        match (hex.len(), channel(0), channel(1), channel(2)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Rgb([r, g, b])),
+            _ => Err(format!("invalid colour '{s}', expected `#rrggbb`")),
+        }
+    }
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b] = self.0;
+        write!(f, "#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+/// The colour of every [`Shade`]. The default follows the colours of
+/// adventofcode.com.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: [Rgb; Shade::ALL.len()],
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            colours: [
+                Rgb([0x0f, 0x0f, 0x23]),
+                Rgb([0x4d, 0x4d, 0x66]),
+                Rgb([0xff, 0xff, 0x66]),
+                Rgb([0x00, 0xcc, 0x00]),
+                Rgb([0x00, 0x99, 0x00]),
+                Rgb([0x1a, 0x1a, 0x40]),
+            ],
+        }
+    }
+}
+
+impl Palette {
+    pub fn get(&self, shade: Shade) -> Rgb {
+        self.colours[shade as usize]
+    }
+
+    pub fn set(&mut self, shade: Shade, colour: Rgb) {
+        self.colours[shade as usize] = colour;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    /// Guesses the format from the extension of `path`.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// A drawing of a grid day, one [`Shade`] per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    cells: Grid<Shade>,
+}
+
+impl Picture {
+    pub fn new(cells: Grid<Shade>) -> Self {
+        Picture { cells }
+    }
+
+    pub fn cells(&self) -> &Grid<Shade> {
+        &self.cells
+    }
+
+    /// Puts `other` to the right of this picture, a column of background in
+    /// between. The shorter one gets background added at the bottom.
+    pub fn beside(self, other: Picture) -> Picture {
+        let height = self.cells.height().max(other.cells.height());
+        let width = self.cells.width() + 1 + other.cells.width();
+        let mut cells = Vec::with_capacity(width * height);
+        let push_row = |cells: &mut Vec<Shade>, picture: &Picture, row: usize| {
+            if row < picture.cells.height() {
+                cells.extend_from_slice(picture.cells.row(row));
+            } else {
+                cells.extend((0..picture.cells.width()).map(|_| Shade::Background));
+            }
+        };
+        for row in 0..height {
+            push_row(&mut cells, &self, row);
+            cells.push(Shade::Background);
+            push_row(&mut cells, &other, row);
+        }
+        Picture::new(Grid::new(width, cells))
+    }
+
+    /// The picture in `format`, every cell `scale` pixels wide and high.
+    pub fn encode(&self, format: Format, palette: &Palette, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        match format {
+            Format::Ppm => {
+                let (width, height) = self.size(scale);
+                let mut res = format!("P6\n{width} {height}\n255\n").into_bytes();
+                res.extend(self.pixels(palette, scale));
+                res
+            }
+            Format::Png => self.png(palette, scale),
+            Format::Svg => self.svg(palette, scale).into_bytes(),
+        }
+    }
+
+    /// Writes the picture to `path`, in the format its extension asks for.
+    pub fn write(&self, path: &Path, palette: &Palette, scale: usize) -> anyhow::Result<()> {
+        let format = Format::from_path(path).ok_or_else(|| {
+            anyhow!(
+                "can't tell the format of {}, use .ppm, .png or .svg",
+                path.display()
+            )
+        })?;
+        fs::write(path, self.encode(format, palette, scale))
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    fn size(&self, scale: usize) -> (usize, usize) {
+        (self.cells.width() * scale, self.cells.height() * scale)
+    }
+
+    /// RGB bytes, row by row.
+    fn pixels(&self, palette: &Palette, scale: usize) -> Vec<u8> {
+        let (width, height) = self.size(scale);
+        let mut res = Vec::with_capacity(width * height * 3);
+        for row in self.cells.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&shade| std::iter::repeat_n(palette.get(shade).0, scale))
+                .flatten()
+                .collect();
+            for _ in 0..scale {
+                res.extend_from_slice(&line);
+            }
+        }
+        res
+    }
+
+    fn png(&self, palette: &Palette, scale: usize) -> Vec<u8> {
+        let (width, height) = self.size(scale);
+        let mut res = Vec::new();
+        let mut encoder = png::Encoder::new(&mut res, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .expect("writing to memory doesn't fail");
+        writer
+            .write_image_data(&self.pixels(palette, scale))
+            .expect("writing to memory doesn't fail");
+        writer.finish().expect("writing to memory doesn't fail");
+        res
+    }
+
+    /// A rectangle per run of equal cells in a row, on top of a background.
+    fn svg(&self, palette: &Palette, scale: usize) -> String {
+        let (width, height) = self.size(scale);
+        let (cols, rows) = (self.cells.width(), self.cells.height());
+        let mut res = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {cols} {rows}\" shape-rendering=\"crispEdges\">\n\
+             <rect width=\"{cols}\" height=\"{rows}\" fill=\"{}\"/>\n",
+            palette.get(Shade::Background)
+        );
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                if run[0] != Shade::Background {
+                    writeln!(
+                        res,
+                        "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                        run.len(),
+                        palette.get(run[0])
+                    )
+                    .unwrap();
+                }
+                x += run.len();
+            }
+        }
+        res.push_str("</svg>\n");
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Picture {
+        use Shade::*;
+        Picture::new(Grid::new(
+            3,
+            vec![Background, Marked, Marked, Inside, Outside, Background],
+        ))
+    }
+
+    #[test]
+    fn colours() {
+        assert_eq!("#0f0F23".parse(), Ok(Rgb([0x0f, 0x0f, 0x23])));
+        assert_eq!("ffff66".parse::<Rgb>().unwrap().to_string(), "#ffff66");
+        assert!("#fff".parse::<Rgb>().is_err());
+        assert!("#gggggg".parse::<Rgb>().is_err());
+        assert_eq!("inside".parse(), Ok(Shade::Inside));
+        assert!("loop".parse::<Shade>().is_err());
+    }
+
+    #[test]
+    fn encodes() {
+        let mut palette = Palette::default();
+        palette.set(Shade::Marked, Rgb([1, 2, 3]));
+
+        let ppm = picture().encode(Format::Ppm, &palette, 2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        // The second pixel of the second row is still the first cell.
+        let pixel = |x: usize, y: usize| &ppm[header.len() + (y * 6 + x) * 3..][..3];
+        assert_eq!(pixel(1, 1), palette.get(Shade::Background).0);
+        assert_eq!(pixel(2, 1), [1, 2, 3]);
+
+        let png = picture().encode(Format::Png, &palette, 2);
+        assert_eq!(&png[1..4], b"PNG");
+
+        let svg = String::from_utf8(picture().encode(Format::Svg, &palette, 2)).unwrap();
+        assert!(svg.contains("width=\"6\" height=\"4\" viewBox=\"0 0 3 2\""));
+        assert!(svg.contains("<rect x=\"1\" y=\"0\" width=\"2\" height=\"1\" fill=\"#010203\"/>"));
+    }
+
+    #[test]
+    fn side_by_side() {
+        use Shade::*;
+        let tall = Picture::new(Grid::new(1, vec![Marked, Marked]));
+        let res = picture().beside(tall);
+        assert_eq!(res.cells().width(), 5);
+        assert_eq!(
+            res.cells().row(1),
+            [Inside, Outside, Background, Background, Marked]
+        );
+        assert_eq!(Format::from_path(Path::new("loop.PNG")), Some(Format::Png));
+        assert_eq!(Format::from_path(Path::new("loop")), None);
+    }
+}
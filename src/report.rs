@@ -0,0 +1,161 @@
+//! Machine-readable reports of a run.
+//!
+//! Fields are only ever added to a report. Renaming or removing one, or
+//! changing its meaning, bumps [`SCHEMA_VERSION`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{Entry, Outcome, Summary};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Failed,
+    Skipped,
+}
+
+/// One day and part. Also the row type of the CSV report, hence it's flat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Row {
+    pub schema_version: u32,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub correct: Option<bool>,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: Option<u128>,
+    pub input_sha256: Option<String>,
+    /// The error of a failed part, or why it was skipped.
+    pub error: Option<String>,
+    /// Last, so the columns of older CSV reports keep their position.
+    pub year: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub schema_version: u32,
+    pub elapsed_ns: u128,
+    pub results: Vec<Row>,
+}
+
+impl From<&Entry> for Row {
+    fn from(entry: &Entry) -> Self {
+        let (status, run, error) = match &entry.outcome {
+            Outcome::Solved(run) => (Status::Solved, Some(run), None),
+            Outcome::Failed(e) => (Status::Failed, None, Some(e.clone())),
+            Outcome::Skipped(reason) => (Status::Skipped, None, Some(reason.clone())),
+        };
+
+        Row {
+            schema_version: SCHEMA_VERSION,
+            day: entry.day,
+            part: entry.part.number(),
+            status,
+            answer: run.map(|run| run.answer.clone()),
+            expected: entry.expected.clone(),
+            correct: entry.is_correct(),
+            parse_ns: run.map(|run| run.parse_time.as_nanos()),
+            solve_ns: run.map(|run| run.solve_time.as_nanos()),
+            input_sha256: entry.input_hash.clone(),
+            error,
+            year: entry.year,
+        }
+    }
+}
+
+impl From<&Summary> for Report {
+    fn from(summary: &Summary) -> Self {
+        Report {
+            schema_version: SCHEMA_VERSION,
+            elapsed_ns: summary.elapsed.as_nanos(),
+            results: summary.entries.iter().map(Row::from).collect(),
+        }
+    }
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports are always serializable")
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// One row per day and part, the overall elapsed time is left out.
+    pub fn to_csv(&self) -> String {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for row in self.results.iter() {
+            writer
+                .serialize(row)
+                .expect("reports are always serializable");
+        }
+        String::from_utf8(writer.into_inner().unwrap()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::solution::{Part, Run};
+
+    fn summary() -> Summary {
+        Summary {
+            entries: vec![
+                Entry {
+                    year: 2023,
+                    day: 1,
+                    part: Part::Two,
+                    outcome: Outcome::Solved(Run {
+                        answer: "281".to_string(),
+                        parse_time: Duration::from_nanos(10),
+                        solve_time: Duration::from_nanos(20),
+                    }),
+                    expected: Some("281".to_string()),
+                    input_hash: Some("abc".to_string()),
+                },
+                Entry {
+                    year: 2023,
+                    day: 5,
+                    part: Part::One,
+                    outcome: Outcome::Failed("day 5, line 1, column 1: oops".to_string()),
+                    expected: None,
+                    input_hash: None,
+                },
+            ],
+            elapsed: Duration::from_nanos(100),
+        }
+    }
+
+    #[test]
+    fn json_report() {
+        let json: serde_json::Value =
+            serde_json::from_str(&Report::from(&summary()).to_json()).unwrap();
+
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["results"][0]["part"], 2);
+        assert_eq!(json["results"][0]["correct"], true);
+        assert_eq!(json["results"][1]["status"], "failed");
+        assert_eq!(json["results"][1]["answer"], serde_json::Value::Null);
+
+        let report = Report::from(&summary());
+        assert_eq!(Report::from_json(&report.to_json()).unwrap(), report);
+    }
+
+    #[test]
+    fn csv_report() {
+        assert_eq!(
+            Report::from(&summary()).to_csv(),
+            "schema_version,day,part,status,answer,expected,correct,parse_ns,solve_ns,input_sha256,error,year\n\
+             1,1,2,solved,281,281,true,10,20,abc,,2023\n\
+             1,5,1,failed,,,false,,,,\"day 5, line 1, column 1: oops\",2023\n"
+        );
+    }
+}
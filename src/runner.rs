@@ -0,0 +1,136 @@
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    answers::Answers,
+    inputs,
+    progress::{Progress, Silent},
+    solution::{Day, Part, Run},
+};
+
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Solved(Run),
+    Failed(String),
+    Skipped(String),
+}
+
+/// A single day and part as run by [`run_all`].
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub expected: Option<String>,
+    /// Hex encoded SHA-256 of the input, if it could be read.
+    pub input_hash: Option<String>,
+}
+
+impl Entry {
+    /// Whether the answer matches the expected one, if there is one to compare with.
+    pub fn is_correct(&self) -> Option<bool> {
+        match (&self.outcome, &self.expected) {
+            (Outcome::Solved(run), Some(expected)) => Some(&run.answer == expected),
+            (Outcome::Failed(_), _) => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub parallel: bool,
+    /// Also run the parts marked as slow in the answers.
+    pub slow: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub entries: Vec<Entry>,
+    /// Wall clock time of the whole run, which is less than the sum of the
+    /// parts when running in parallel.
+    pub elapsed: Duration,
+}
+
+fn hash_input(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Runs a single part against `input`.
+pub fn run_part(
+    day: &Day,
+    part: Part,
+    input: &str,
+    answers: &Answers,
+    progress: &dyn Progress,
+) -> Entry {
+    Entry {
+        year: day.year,
+        day: day.day,
+        part,
+        outcome: match day.run_with_progress(input, part, progress) {
+            Ok(run) => Outcome::Solved(run),
+            Err(e) => Outcome::Failed(e.to_string()),
+        },
+        expected: answers.get(day.year, day.day, part).map(str::to_string),
+        input_hash: Some(hash_input(input)),
+    }
+}
+
+/// Runs every part of `days` against its real input. Progress is only
+/// reported when running one part after the other.
+pub fn run_all<'a>(
+    days: impl IntoIterator<Item = &'a Day>,
+    answers: &Answers,
+    options: &Options,
+    progress: &dyn Progress,
+) -> Summary {
+    let start = Instant::now();
+    let jobs: Vec<(&Day, Part)> = days
+        .into_iter()
+        .flat_map(|day| day.parts().iter().map(move |part| (day, *part)))
+        .collect();
+
+    let run_job = |&(day, part): &(&Day, Part), progress: &dyn Progress| {
+        let not_run = |outcome| Entry {
+            year: day.year,
+            day: day.day,
+            part,
+            outcome,
+            expected: answers.get(day.year, day.day, part).map(str::to_string),
+            input_hash: None,
+        };
+
+        if answers.is_slow(day.year, day.day, part) && !options.slow {
+            return not_run(Outcome::Skipped("slow".to_string()));
+        }
+        let path = inputs::path(day.year, day.day);
+        match fs::read_to_string(&path) {
+            Ok(input) => run_part(day, part, &input, answers, progress),
+            Err(e) => not_run(Outcome::Failed(format!(
+                "failed to read {}: {e}",
+                path.display()
+            ))),
+        }
+    };
+
+    let entries = if options.parallel {
+        jobs.par_iter().map(|job| run_job(job, &Silent)).collect()
+    } else {
+        jobs.iter().map(|job| run_job(job, progress)).collect()
+    };
+
+    Summary {
+        entries,
+        elapsed: start.elapsed(),
+    }
+}
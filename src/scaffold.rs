@@ -0,0 +1,240 @@
+//! Creating the files for a new day: the solution skeleton, its registration
+//! in the year module, an example to fill in and an empty input placeholder.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+use crate::{fixtures, inputs};
+
+const TEMPLATE: &str = r#"use nom::character::complete::not_line_ending;
+
+use crate::{
+    common::{
+        parse::{self, lines},
+        ParseError,
+    },
+    solution::Solution,
+};
+
+const DAY: u8 = {day};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "{title}";
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse::finish(DAY, input, lines(not_line_ending))
+    }
+
+    fn part1(_input: &Vec<&str>) -> anyhow::Result<u64> {
+        anyhow::bail!("not solved yet")
+    }
+
+    fn part2(_input: &Vec<&str>) -> anyhow::Result<u64> {
+        anyhow::bail!("not solved yet")
+    }
+}
+"#;
+
+/// The answers next to the example, wrong until they're filled in.
+const EXAMPLE_ANSWERS: &str = "part1 = 0
+part2 = 0
+";
+
+/// The files written by [`create`], relative to the root it was given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Created {
+    pub source: PathBuf,
+    pub module: PathBuf,
+    /// The example input, its answers being next to it.
+    pub example: PathBuf,
+    pub input: PathBuf,
+}
+
+/// Creates `src/y<year>/day<day>.rs` under `root` and registers it in
+/// `src/y<year>.rs`, along with an empty example fixture. An empty input
+/// placeholder is created unless there already is an input. Nothing is
+/// written if the day already exists.
+pub fn create(root: &Path, year: u16, day: u8, title: &str) -> anyhow::Result<Created> {
+    if !(1..=25).contains(&day) {
+        bail!("there is no day {day}, days go from 1 to 25");
+    }
+    if title.contains(['"', '\\', '\n']) {
+        bail!("the title can't contain quotes, backslashes or line breaks");
+    }
+
+    let module = PathBuf::from(format!("src/y{year}.rs"));
+    let source = PathBuf::from(format!("src/y{year}/day{day}.rs"));
+    let example = Path::new(fixtures::DEFAULT_DIR).join(format!("{year}/day{day}/example1.txt"));
+    let input = inputs::path(year, day);
+
+    for path in [&source, &example] {
+        if root.join(path).exists() {
+            bail!("{} already exists", path.display());
+        }
+    }
+    let content = fs::read_to_string(root.join(&module))
+        .with_context(|| format!("failed to read {}, is {year} set up?", module.display()))?;
+    let registered = register(&content, day)
+        .with_context(|| format!("failed to register day {day} in {}", module.display()))?;
+
+    let skeleton = TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{title}", title);
+    write(root, &source, &skeleton)?;
+    write(root, &module, &registered)?;
+    write(root, &example, "")?;
+    write(root, &example.with_extension("toml"), EXAMPLE_ANSWERS)?;
+    if !root.join(&input).exists() {
+        write(root, &input, "")?;
+    }
+
+    Ok(Created {
+        source,
+        module,
+        example,
+        input,
+    })
+}
+
+fn write(root: &Path, path: &Path, content: &str) -> anyhow::Result<()> {
+    let path = root.join(path);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    fs::write(&path, content).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Adds the `mod` declaration of `day`, sorted like rustfmt would, and its
+/// entry in `DAYS`, in calendar order.
+fn register(module: &str, day: u8) -> anyhow::Result<String> {
+    let mut lines: Vec<String> = module.lines().map(String::from).collect();
+
+    let name = format!("day{day}");
+    let declaration = format!("pub mod {name};");
+    let mod_name = |line: &str| {
+        line.strip_prefix("pub mod ")?
+            .strip_suffix(';')
+            .map(String::from)
+    };
+    let mods = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day"))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    if mods.iter().any(|&idx| lines[idx] == declaration) {
+        bail!("{name} is already declared");
+    }
+    let Some(&last_mod) = mods.last() else {
+        bail!("no `pub mod dayN;` declarations found");
+    };
+    let at = mods
+        .iter()
+        .copied()
+        .find(|&idx| mod_name(&lines[idx]).is_some_and(|other| other > name))
+        .unwrap_or(last_mod + 1);
+    lines.insert(at, declaration);
+
+    let Some(start) = lines.iter().position(|line| line.contains("DAYS: &[Day]")) else {
+        bail!("no `DAYS` list found");
+    };
+    let Some(end) = lines[start..].iter().position(|line| line == "];") else {
+        bail!("the `DAYS` list isn't closed");
+    };
+    let entry_day = |line: &str| -> Option<u8> {
+        line.trim()
+            .strip_prefix("Day::new::<day")?
+            .split_once("::")?
+            .0
+            .parse()
+            .ok()
+    };
+    let at = (start + 1..start + end)
+        .find(|&idx| entry_day(&lines[idx]).is_some_and(|other| other > day))
+        .unwrap_or(start + end);
+    lines.insert(at, format!("    Day::new::<day{day}::Day{day}>(),"));
+
+    let mut res = lines.join("\n");
+    res.push('\n');
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE: &str = "use crate::solution::Day;
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+
+pub const YEAR: u16 = 2023;
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day10::Day10>(),
+];
+";
+
+    #[test]
+    fn registers_in_order() {
+        let res = register(MODULE, 3).unwrap();
+        assert!(res.contains("pub mod day2;\npub mod day3;\n\npub const"));
+        assert!(
+            res.contains("<day2::Day2>(),\n    Day::new::<day3::Day3>(),\n    Day::new::<day10")
+        );
+
+        let res = register(MODULE, 11).unwrap();
+        assert!(res.contains("pub mod day10;\npub mod day11;\npub mod day2;"));
+        assert!(res.contains("<day11::Day11>(),\n];"));
+
+        let res = register(MODULE, 12).unwrap();
+        assert!(res.contains("pub mod day10;\npub mod day12;\npub mod day2;"));
+
+        assert!(register(MODULE, 10).is_err());
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc23-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write(&root, Path::new("src/y2023.rs"), MODULE).unwrap();
+        write(&root, Path::new("input/2023/day3.txt"), "1 2 3\n").unwrap();
+
+        let created = create(&root, 2023, 3, "Gear Ratios").unwrap();
+        let source = fs::read_to_string(root.join(&created.source)).unwrap();
+        assert!(source.contains("pub struct Day3;"));
+        assert!(source.contains("\"Gear Ratios\""));
+        assert_eq!(
+            fs::read_to_string(root.join(created.example.with_extension("toml"))).unwrap(),
+            EXAMPLE_ANSWERS
+        );
+        // An existing input is kept.
+        assert_eq!(
+            fs::read_to_string(root.join(&created.input)).unwrap(),
+            "1 2 3\n"
+        );
+
+        let module = fs::read_to_string(root.join("src/y2023.rs")).unwrap();
+        assert!(create(&root, 2023, 3, "Gear Ratios").is_err());
+        assert!(create(&root, 2023, 10, "Pipe Maze").is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2023.rs")).unwrap(),
+            module
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use tracing::{debug, debug_span, info_span};
+
+use crate::{
+    animate::Show,
+    common::ParseError,
+    explain::ExplainStep,
+    progress::{Progress, Silent},
+    render::Picture,
+};
+
+/// One day of the calendar: a parser for the puzzle input and a solver per part.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// The puzzle title as shown on adventofcode.com.
+    const TITLE: &'static str;
+    /// Only the last day of a calendar has a single part.
+    const HAS_PART2: bool = true;
+
+    /// The parsed puzzle input, allowed to borrow from the raw input.
+    type Input<'a>;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<Self::Answer>;
+
+    fn part2(_input: &Self::Input<'_>) -> anyhow::Result<Self::Answer> {
+        anyhow::bail!("day {} has no second part", Self::DAY)
+    }
+
+    /// Like [`Solution::part1`], telling `progress` how far along it is. Only
+    /// worth overriding for parts that take a while.
+    fn part1_with_progress(
+        input: &Self::Input<'_>,
+        _progress: &dyn Progress,
+    ) -> anyhow::Result<Self::Answer> {
+        Self::part1(input)
+    }
+
+    /// Like [`Solution::part2`], telling `progress` how far along it is.
+    fn part2_with_progress(
+        input: &Self::Input<'_>,
+        _progress: &dyn Progress,
+    ) -> anyhow::Result<Self::Answer> {
+        Self::part2(input)
+    }
+
+    /// How `part` arrives at its answer, step by step. Empty for the days
+    /// that can't tell.
+    fn explain(_input: &Self::Input<'_>, _part: Part) -> anyhow::Result<Vec<ExplainStep>> {
+        Ok(Vec::new())
+    }
+
+    /// A picture of the input and how it was solved, `None` for the days
+    /// that aren't drawn.
+    fn render(_input: &Self::Input<'_>) -> anyhow::Result<Option<Picture>> {
+        Ok(None)
+    }
+
+    /// Replays the walk through the input, handing `show` a [`Frame`](crate::animate::Frame) per
+    /// step for as long as it asks to continue. `false` for the days that
+    /// don't walk anywhere.
+    fn animate(_input: &Self::Input<'_>, _show: &mut Show<'_>) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
+    /// Solves `part` with some of its constants replaced by `params`, like
+    /// the examples that use a smaller expansion factor than the real
+    /// puzzle. Only days that have such constants override this.
+    fn part_with_params(
+        input: &Self::Input<'_>,
+        part: Part,
+        params: &Params,
+    ) -> anyhow::Result<Self::Answer> {
+        if let Some(name) = params.keys().next() {
+            anyhow::bail!("day {} has no parameter `{name}`", Self::DAY);
+        }
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Parses `input` and solves the first part of it.
+    fn solve_part1(input: &str) -> anyhow::Result<Self::Answer> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    /// Parses `input` and solves the second part of it.
+    fn solve_part2(input: &str) -> anyhow::Result<Self::Answer> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+/// Named values replacing constants of a day, see
+/// [`Solution::part_with_params`].
+pub type Params = BTreeMap<String, i64>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => Err(format!("there is no part {n}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The outcome of running a single part against an input.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    Parse(ParseError),
+    Solve(anyhow::Error),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Parse(e) => write!(f, "{e}"),
+            RunError::Solve(e) => write!(f, "{e:#}"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+/// A type-erased [`Solution`], as stored in the registry.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub has_part2: bool,
+    run: fn(&str, Part, &dyn Progress) -> Result<Run, RunError>,
+    explain: fn(&str, Part) -> Result<Vec<ExplainStep>, RunError>,
+    render: fn(&str) -> Result<Option<Picture>, RunError>,
+    animate: fn(&str, &mut Show<'_>) -> Result<bool, RunError>,
+    solve: fn(&str, Part, &Params) -> Result<String, RunError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            has_part2: S::HAS_PART2,
+            run: run::<S>,
+            explain: explain::<S>,
+            render: render::<S>,
+            animate: animate::<S>,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn parts(&self) -> &'static [Part] {
+        if self.has_part2 {
+            &Part::ALL
+        } else {
+            &Part::ALL[..1]
+        }
+    }
+
+    pub fn run(&self, input: &str, part: Part) -> Result<Run, RunError> {
+        (self.run)(input, part, &Silent)
+    }
+
+    pub fn run_with_progress(
+        &self,
+        input: &str,
+        part: Part,
+        progress: &dyn Progress,
+    ) -> Result<Run, RunError> {
+        (self.run)(input, part, progress)
+    }
+
+    /// See [`Solution::explain`].
+    pub fn explain(&self, input: &str, part: Part) -> Result<Vec<ExplainStep>, RunError> {
+        (self.explain)(input, part)
+    }
+
+    /// See [`Solution::render`].
+    pub fn render(&self, input: &str) -> Result<Option<Picture>, RunError> {
+        (self.render)(input)
+    }
+
+    /// See [`Solution::animate`].
+    pub fn animate(&self, input: &str, show: &mut Show<'_>) -> Result<bool, RunError> {
+        (self.animate)(input, show)
+    }
+
+    /// The answer to `part` of `input`. See [`Solution::part_with_params`].
+    pub fn solve(&self, input: &str, part: Part, params: &Params) -> Result<String, RunError> {
+        (self.solve)(input, part, params)
+    }
+}
+
+fn explain<S: Solution>(input: &str, part: Part) -> Result<Vec<ExplainStep>, RunError> {
+    let parsed = S::parse(input).map_err(RunError::Parse)?;
+    S::explain(&parsed, part).map_err(RunError::Solve)
+}
+
+fn render<S: Solution>(input: &str) -> Result<Option<Picture>, RunError> {
+    let parsed = S::parse(input).map_err(RunError::Parse)?;
+    S::render(&parsed).map_err(RunError::Solve)
+}
+
+fn animate<S: Solution>(input: &str, show: &mut Show<'_>) -> Result<bool, RunError> {
+    let parsed = S::parse(input).map_err(RunError::Parse)?;
+    S::animate(&parsed, show).map_err(RunError::Solve)
+}
+
+fn solve<S: Solution>(input: &str, part: Part, params: &Params) -> Result<String, RunError> {
+    let parsed = S::parse(input).map_err(RunError::Parse)?;
+    S::part_with_params(&parsed, part, params)
+        .map(|answer| answer.to_string())
+        .map_err(RunError::Solve)
+}
+
+fn run<S: Solution>(input: &str, part: Part, progress: &dyn Progress) -> Result<Run, RunError> {
+    let _span = info_span!("run", year = S::YEAR, day = S::DAY, %part).entered();
+
+    let start = Instant::now();
+    let parsed = debug_span!("parse")
+        .in_scope(|| S::parse(input))
+        .map_err(RunError::Parse)?;
+    let parse_time = start.elapsed();
+    debug!(?parse_time, "parsed");
+
+    let start = Instant::now();
+    let answer = debug_span!("solve")
+        .in_scope(|| match part {
+            Part::One => S::part1_with_progress(&parsed, progress),
+            Part::Two => S::part2_with_progress(&parsed, progress),
+        })
+        .map_err(RunError::Solve)?;
+    let solve_time = start.elapsed();
+    debug!(%answer, ?solve_time, "solved");
+
+    Ok(Run {
+        answer: answer.to_string(),
+        parse_time,
+        solve_time,
+    })
+}
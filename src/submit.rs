@@ -0,0 +1,313 @@
+//! Submitting answers, and remembering what was submitted so the same wrong
+//! answer is never sent twice.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, solution::Part};
+
+pub fn history_path(year: u16) -> PathBuf {
+    PathBuf::from(format!("submissions/{year}.jsonl"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the previous answer, nothing was checked.
+    Wait(Duration),
+    /// The part was solved before, nothing was checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Parses the page returned after posting an answer.
+    pub fn from_response(body: &str) -> anyhow::Result<Self> {
+        if body.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if body.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else if body.contains("That's not the right answer") {
+            Ok(Verdict::Wrong)
+        } else if body.contains("You gave an answer too recently") {
+            Ok(Verdict::Wait(
+                parse_wait(body).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::AlreadySolved)
+        } else {
+            bail!("unrecognized response to an answer")
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// Extracts the duration of "You have 1m 5s left to wait".
+fn parse_wait(body: &str) -> Option<Duration> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted for a year so far, one JSON object per line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if there is no file yet.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+
+        let submissions = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(n, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("{}:{}: invalid submission", path.display(), n + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(History { submissions })
+    }
+
+    fn append(&mut self, path: &Path, submission: Submission) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut line = serde_json::to_string(&submission)?;
+        line.push('\n');
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Fails if `answer` is known to be wrong without asking the server.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> anyhow::Result<()> {
+        let submissions = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part.number());
+        let number = answer.parse::<i128>().ok();
+
+        for submission in submissions {
+            let known = submission.answer.parse::<i128>().ok();
+            match submission.verdict {
+                Verdict::Correct => {
+                    bail!(
+                        "day {day} part {part} is already solved with {}",
+                        submission.answer
+                    )
+                }
+                verdict if verdict.is_wrong() && submission.answer == answer => {
+                    bail!("{answer} was already submitted for day {day} part {part} and is wrong")
+                }
+                Verdict::TooHigh if number.zip(known).is_some_and(|(n, high)| n >= high) => {
+                    bail!("{answer} is too high, {} already was", submission.answer)
+                }
+                Verdict::TooLow if number.zip(known).is_some_and(|(n, low)| n <= low) => {
+                    bail!("{answer} is too low, {} already was", submission.answer)
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the history at `history` already rules it out, and
+/// records the verdict there.
+pub fn submit(
+    client: &Client,
+    history: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<Verdict> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        bail!("refusing to submit an empty answer");
+    }
+    let mut submissions = History::load(history)?;
+    submissions.check(day, part, answer)?;
+
+    let level = part.number().to_string();
+    let response = client.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    if response.status != 200 {
+        bail!("submitting failed with status {}", response.status);
+    }
+    let verdict = Verdict::from_response(&response.body)?;
+
+    let submitted_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    submissions.append(
+        history,
+        Submission {
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        },
+    )?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn verdicts() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Verdict::Wrong,
+            ),
+            (
+                "You gave an answer too recently. You have 1m 5s left to wait.",
+                Verdict::Wait(Duration::from_secs(65)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+        ];
+        for (message, verdict) in cases {
+            assert_eq!(Verdict::from_response(&page(message)).unwrap(), verdict);
+        }
+        assert!(Verdict::from_response("<html></html>").is_err());
+    }
+
+    #[test]
+    fn rejects_known_wrong() {
+        let submission = |answer: &str, verdict| Submission {
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 0,
+        };
+        let history = History {
+            submissions: vec![
+                submission("100", Verdict::TooHigh),
+                submission("10", Verdict::TooLow),
+                submission("42", Verdict::Wrong),
+                submission("50", Verdict::Wait(Duration::from_secs(30))),
+            ],
+        };
+
+        assert!(history.check(1, Part::One, "42").is_err());
+        assert!(history.check(1, Part::One, "100").is_err());
+        assert!(history.check(1, Part::One, "150").is_err());
+        assert!(history.check(1, Part::One, "5").is_err());
+        assert!(history.check(1, Part::One, "50").is_ok());
+        assert!(history.check(1, Part::Two, "42").is_ok());
+    }
+
+    #[test]
+    fn submit_and_record() {
+        let dir = std::env::temp_dir().join(format!("aoc23-submit-{}", std::process::id()));
+        let history = dir.join("2023.jsonl");
+        let _ = fs::remove_dir_all(&dir);
+
+        let (url, server) = mock::serve(vec![(
+            200,
+            page("That's not the right answer; your answer is too low."),
+        )]);
+        let client = Client::new("secret")
+            .with_base_url(url)
+            .with_min_interval(Duration::ZERO);
+
+        assert_eq!(
+            submit(&client, &history, 2023, 4, Part::Two, "1234\n").unwrap(),
+            Verdict::TooLow
+        );
+        // Ruled out locally, the mock would not answer a second request.
+        assert!(submit(&client, &history, 2023, 4, Part::Two, "1000").is_err());
+
+        let request = server.join().unwrap().remove(0);
+        assert!(request.starts_with("POST /2023/day/4/answer "));
+        assert!(request.ends_with("level=2&answer=1234"));
+
+        let recorded = History::load(&history).unwrap().submissions;
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(
+            (recorded[0].answer.as_str(), recorded[0].verdict),
+            ("1234", Verdict::TooLow)
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
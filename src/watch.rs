@@ -0,0 +1,210 @@
+//! Noticing changes to the files of a day while it's being solved, and how
+//! its answers changed from one run to the next.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{
+    fixtures, inputs,
+    report::{Report, Status},
+};
+
+/// The files that mean running a day again when they change: its source,
+/// the common code, its examples and its real input. Relative to the
+/// repository root.
+pub fn paths(year: u16, day: u8) -> Vec<PathBuf> {
+    vec![
+        PathBuf::from(format!("src/y{year}/day{day}.rs")),
+        PathBuf::from("src/common.rs"),
+        PathBuf::from("src/common"),
+        Path::new(fixtures::DEFAULT_DIR).join(format!("{year}/day{day}")),
+        inputs::path(year, day),
+    ]
+}
+
+/// When every watched file was last modified.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    /// Looks at `paths` under `root`, directories with everything in them.
+    /// Paths that don't exist are left out, so creating them is a change.
+    pub fn take(root: &Path, paths: &[PathBuf]) -> Self {
+        let mut res = Snapshot::default();
+        for path in paths {
+            res.add(&root.join(path));
+        }
+        res
+    }
+
+    fn add(&mut self, path: &Path) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+                self.add(&entry.path());
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            self.0.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    /// The files that were created, removed or modified since `earlier`.
+    pub fn changes(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let mut res: Vec<PathBuf> = self
+            .0
+            .iter()
+            .filter(|(path, modified)| earlier.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+        res.extend(
+            earlier
+                .0
+                .keys()
+                .filter(|path| !self.0.contains_key(*path))
+                .cloned(),
+        );
+        res.sort();
+        res
+    }
+}
+
+/// A line per part of `current` with its answer and how it differs from the
+/// one in `previous`.
+pub fn diff(previous: Option<&Report>, current: &Report) -> Vec<String> {
+    let answers = |report: &Report| -> BTreeMap<u8, Option<String>> {
+        report
+            .results
+            .iter()
+            .map(|row| (row.part, row.answer.clone()))
+            .collect()
+    };
+    let before = previous.map(answers).unwrap_or_default();
+
+    current
+        .results
+        .iter()
+        .map(|row| {
+            let Some(answer) = row.answer.as_ref().filter(|_| row.status == Status::Solved) else {
+                let error = row.error.as_deref().unwrap_or("no answer");
+                return format!("part {}: failed, {error}", row.part);
+            };
+            let change = match before.get(&row.part) {
+                None if previous.is_none() => String::new(),
+                Some(Some(old)) if old == answer => " (unchanged)".to_string(),
+                Some(Some(old)) => format!(" (was {old})"),
+                _ => " (new)".to_string(),
+            };
+            let verdict = match (row.correct, &row.expected) {
+                (Some(true), _) => ", correct".to_string(),
+                (Some(false), Some(expected)) => format!(", wrong, expected {expected}"),
+                _ => String::new(),
+            };
+            format!("part {}: {answer}{change}{verdict}", row.part)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, time::Duration};
+
+    use super::*;
+    use crate::report::{Row, SCHEMA_VERSION};
+
+    #[test]
+    fn notices_changes() {
+        let root = std::env::temp_dir().join(format!("aoc23-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/common")).unwrap();
+        fs::write(root.join("src/common/grid.rs"), "").unwrap();
+        fs::write(root.join("src/common.rs"), "").unwrap();
+        let paths = paths(2023, 3);
+
+        let before = Snapshot::take(&root, &paths);
+        assert_eq!(before.0.len(), 2);
+        assert!(Snapshot::take(&root, &paths).changes(&before).is_empty());
+
+        let grid = root.join("src/common/grid.rs");
+        File::options()
+            .write(true)
+            .open(&grid)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        fs::create_dir_all(root.join("input/2023")).unwrap();
+        fs::write(root.join("input/2023/day3.txt"), "467..114..\n").unwrap();
+        let example = root.join("tests/fixtures/2023/day3/example1.toml");
+        fs::create_dir_all(example.parent().unwrap()).unwrap();
+        fs::write(&example, "part1 = 4361\n").unwrap();
+        // Other days don't matter.
+        fs::write(root.join("input/2023/day4.txt"), "").unwrap();
+        assert_eq!(
+            Snapshot::take(&root, &paths).changes(&before),
+            [root.join("input/2023/day3.txt"), grid, example]
+        );
+
+        fs::remove_file(root.join("src/common.rs")).unwrap();
+        let after = Snapshot::take(&root, &paths);
+        assert!(after.changes(&before).contains(&root.join("src/common.rs")));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn report(answers: &[(u8, Option<&str>)]) -> Report {
+        Report {
+            schema_version: SCHEMA_VERSION,
+            elapsed_ns: 0,
+            results: answers
+                .iter()
+                .map(|&(part, answer)| Row {
+                    schema_version: SCHEMA_VERSION,
+                    day: 3,
+                    part,
+                    status: if answer.is_some() {
+                        Status::Solved
+                    } else {
+                        Status::Failed
+                    },
+                    answer: answer.map(str::to_string),
+                    expected: Some("4361".to_string()),
+                    correct: answer.map(|answer| answer == "4361"),
+                    parse_ns: None,
+                    solve_ns: None,
+                    input_sha256: None,
+                    error: answer.is_none().then(|| "not solved yet".to_string()),
+                    year: 2023,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let first = report(&[(1, Some("4360")), (2, None)]);
+        assert_eq!(
+            diff(None, &first),
+            [
+                "part 1: 4360, wrong, expected 4361",
+                "part 2: failed, not solved yet"
+            ]
+        );
+
+        let second = report(&[(1, Some("4361")), (2, Some("467835"))]);
+        assert_eq!(
+            diff(Some(&first), &second),
+            [
+                "part 1: 4361 (was 4360), correct",
+                "part 2: 467835 (new), wrong, expected 4361"
+            ]
+        );
+        assert_eq!(
+            diff(Some(&second), &second)[0],
+            "part 1: 4361 (unchanged), correct"
+        );
+    }
+}
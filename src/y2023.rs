@@ -0,0 +1,30 @@
+use crate::solution::Day;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub const YEAR: u16 = 2023;
+
+/// Every implemented day of the year, in calendar order.
+pub static DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    Day::new::<day7::Day7>(),
+    Day::new::<day8::Day8>(),
+    Day::new::<day9::Day9>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+];
@@ -0,0 +1,134 @@
+use pcre2::bytes::{Captures, Regex};
+
+use crate::{
+    common::ParseError,
+    explain::ExplainStep,
+    solution::{Part, Solution},
+};
+
+const DAY: u8 = 1;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    /// Lines are only interpreted by the parts, as the first one only accepts digits.
+    type Input<'a> = &'a str;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &&str) -> anyhow::Result<u32> {
+        Ok(calibrations(input, false)?
+            .into_iter()
+            .map(|(_, digits)| calibration_value(digits))
+            .sum())
+    }
+
+    fn part2(input: &&str) -> anyhow::Result<u32> {
+        Ok(calibrations(input, true)?
+            .into_iter()
+            .map(|(_, digits)| calibration_value(digits))
+            .sum())
+    }
+
+    fn explain(input: &&str, part: Part) -> anyhow::Result<Vec<ExplainStep>> {
+        Ok(calibrations(input, part == Part::Two)?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (line, digits))| {
+                ExplainStep::new(
+                    format!("line {}", idx + 1),
+                    format!(
+                        "first {} and last {} in {line}, calibration value {}",
+                        digits[0],
+                        digits[1],
+                        calibration_value(digits)
+                    ),
+                )
+            })
+            .collect())
+    }
+}
+
+/// Every non-empty line with its first and last digit, spelled out ones
+/// included if `spelled`.
+fn calibrations(input: &str, spelled: bool) -> Result<Vec<(&str, [&str; 2])>, ParseError> {
+    let re = Regex::new(r"(?=(one|two|three|four|five|six|seven|eight|nine|\d))").unwrap();
+    let mut res = Vec::new();
+    for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let digits = if spelled {
+            let captures = re.captures_iter(line.as_bytes()).map(|c| c.unwrap());
+            let captures: Vec<Captures> = captures.collect();
+            let word = |captures: Option<&Captures>| {
+                captures.map(|c| {
+                    let word = c.get(1).unwrap();
+                    &line[word.start()..word.end()]
+                })
+            };
+            word(captures.first()).zip(word(captures.last()))
+        } else {
+            let digit = |idx: usize| &line[idx..idx + 1];
+            line.find(|c: char| c.is_ascii_digit())
+                .map(digit)
+                .zip(line.rfind(|c: char| c.is_ascii_digit()).map(digit))
+        };
+
+        let Some((first, last)) = digits else {
+            let message = if spelled {
+                "line contains no digit or spelled out number"
+            } else {
+                "line contains no digit"
+            };
+            return Err(ParseError::at(DAY, input, line, message));
+        };
+        res.push((line, [first, last]));
+    }
+    Ok(res)
+}
+
+fn calibration_value(digits: [&str; 2]) -> u32 {
+    let digit = |word: &str| match word {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        n => n.parse().unwrap(),
+    };
+    digit(digits[0]) * 10 + digit(digits[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_digit() {
+        let err = Day1::solve_part1("1abc2\npqrstu\n").unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn explains_digits() {
+        const EXAMPLE: &str = "two1nine\neightwothree\nxtwone3four";
+        let steps = Day1::explain(&EXAMPLE, Part::Two).unwrap();
+        assert_eq!(steps.len(), 3);
+        assert_eq!(
+            steps[1].to_string(),
+            "line 2: first eight and last three in eightwothree, calibration value 83"
+        );
+        let err = Day1::explain(&EXAMPLE, Part::One).unwrap_err();
+        assert!(err.downcast::<ParseError>().is_ok());
+    }
+}
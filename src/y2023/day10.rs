@@ -0,0 +1,445 @@
+use std::collections::HashSet;
+
+use tracing::debug;
+
+use crate::{
+    animate::{Frame, Show},
+    common::{
+        geom::{Direction, Point},
+        grid::Grid,
+        ParseError,
+    },
+    profile,
+    render::{Picture, Shade},
+    solution::Solution,
+};
+
+const DAY: u8 = 10;
+
+type Number = u32;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Pipe([Direction; 2]),
+    Origin,
+    Ground,
+}
+
+impl Tile {
+    fn has_direction(&self, dir: Direction) -> bool {
+        if self == &Self::Origin {
+            true
+        } else if let Self::Pipe(directions) = self {
+            directions.contains(&dir)
+        } else {
+            false
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Direction::*;
+        Ok(match value {
+            '|' => Self::Pipe([North, South]),
+            '-' => Self::Pipe([East, West]),
+            'L' => Self::Pipe([North, East]),
+            'J' => Self::Pipe([North, West]),
+            '7' => Self::Pipe([South, West]),
+            'F' => Self::Pipe([South, East]),
+            '.' => Self::Ground,
+            'S' => Self::Origin,
+            c => return Err(format!("unexpected character {c:?}")),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    grid: Grid<Tile>,
+    origin: Point,
+}
+
+impl Map {
+    pub fn get(&self, pos: Point) -> Option<&Tile> {
+        self.grid.get(pos.to_coord()?)
+    }
+
+    pub fn walk(&self, from: Point, dir: Direction) -> Option<(Point, Tile)> {
+        let from_tile = self.get(from)?;
+        let next_tile = self.get(from + dir)?;
+
+        if from_tile.has_direction(dir) && next_tile.has_direction(dir.opposite()) {
+            Some((from + dir, *next_tile))
+        } else {
+            None
+        }
+    }
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(DAY, value)?;
+        let Some(origin) = grid.position(|tile| tile == &Tile::Origin) else {
+            return Err(ParseError::at_end(DAY, value, "no starting tile 'S' found"));
+        };
+
+        Ok(Map {
+            grid,
+            origin: origin.into(),
+        })
+    }
+}
+
+/// The tiles reached following the pipes out of the start, ending back at
+/// the start or where the pipes stop connecting.
+struct Walk<'a> {
+    map: &'a Map,
+    at: Point,
+    /// Where the next step goes.
+    heading: Direction,
+    done: bool,
+    /// Whether the walk came back to the start, rather than getting stuck.
+    back: bool,
+}
+
+impl<'a> Walk<'a> {
+    fn new(map: &'a Map, heading: Direction) -> Self {
+        Walk {
+            map,
+            at: map.origin,
+            heading,
+            done: false,
+            back: false,
+        }
+    }
+}
+
+impl Iterator for Walk<'_> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        if self.done {
+            return None;
+        }
+        profile::progress!("day10 step");
+        let Some((pos, tile)) = self.map.walk(self.at, self.heading) else {
+            self.done = true;
+            return None;
+        };
+        self.at = pos;
+        match tile {
+            Tile::Ground => unreachable!("How did you end up on the ground? O.o"),
+            Tile::Origin => {
+                self.done = true;
+                self.back = true;
+            }
+            Tile::Pipe(ends) => {
+                if ends[0] == self.heading.opposite() {
+                    self.heading = ends[1]
+                } else {
+                    self.heading = ends[0]
+                }
+            }
+        }
+        Some(pos)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input<'a> = Map;
+    type Answer = Number;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Map) -> anyhow::Result<Number> {
+        use Direction::*;
+
+        let mut res = 0;
+        for dir in [North, South, West, East] {
+            let mut walk = Walk::new(map, dir);
+            let length = walk.by_ref().count() as Number;
+            if walk.back {
+                debug!(?dir, length, "walked the loop");
+                res = res.max(length / 2);
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn part2(map: &Map) -> anyhow::Result<Number> {
+        let loop_tiles = find_loop(map);
+        let outside = outside_tiles(map, &loop_tiles);
+        Ok(map.grid.len() as u32 - outside.len() as u32 - loop_tiles.len() as u32)
+    }
+
+    /// The loop is marked, the tiles it encloses are inside and the rest
+    /// outside, stray pipes included.
+    fn render(map: &Map) -> anyhow::Result<Option<Picture>> {
+        let loop_tiles = find_loop(map);
+        let outside = outside_tiles(map, &loop_tiles);
+        let shades = map
+            .grid
+            .iter()
+            .map(|(coord, _)| {
+                let pos = Point::from(coord);
+                if loop_tiles.contains(&pos) {
+                    Shade::Marked
+                } else if outside.contains(&pos) {
+                    Shade::Outside
+                } else {
+                    Shade::Inside
+                }
+            })
+            .collect();
+        Ok(Some(Picture::new(Grid::new(map.grid.width(), shades))))
+    }
+
+    /// The walks out of the start of part 1, the tile reached highlighted
+    /// and the pipes behind it in bold, until one comes back to the start.
+    fn animate(map: &Map, show: &mut Show<'_>) -> anyhow::Result<bool> {
+        use Direction::*;
+
+        let frame = |walked: &Grid<bool>, at: Point, status: String| Frame {
+            lines: map
+                .grid
+                .rows()
+                .zip(walked.rows())
+                .map(|(tiles, walked)| {
+                    tiles
+                        .iter()
+                        .zip(walked)
+                        .map(|(tile, &walked)| tile.glyph(walked))
+                        .collect()
+                })
+                .collect(),
+            highlight: at.to_coord(),
+            status,
+        };
+
+        for dir in [North, South, West, East] {
+            let mut walked = Grid::new(map.grid.width(), vec![false; map.grid.len()]);
+            let mut walk = Walk::new(map, dir);
+            let mut current = map.origin;
+            for step in 0.. {
+                let heading = walk.heading;
+                let Some(pos) = walk.next() else {
+                    let status = format!("step {step}, no way {heading:?}");
+                    if show(frame(&walked, current, status)).is_break() {
+                        return Ok(true);
+                    }
+                    break;
+                };
+                let status = format!("step {step}, heading {heading:?}");
+                if show(frame(&walked, current, status)).is_break() {
+                    return Ok(true);
+                }
+                walked[current.to_coord().unwrap()] = true;
+                current = pos;
+                if walk.back {
+                    let status = format!(
+                        "step {}, back at the start, the farthest tile is {} steps away",
+                        step + 1,
+                        (step + 1) / 2
+                    );
+                    let _ = show(frame(&walked, current, status));
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl Tile {
+    /// How the tile is drawn, in bold once the walk went through it.
+    fn glyph(self, walked: bool) -> char {
+        use Direction::*;
+        let (thin, bold) = match self {
+            Tile::Origin => return 'S',
+            Tile::Ground => return ' ',
+            Tile::Pipe(ends) => match ends {
+                [North, South] => ('│', '┃'),
+                [East, West] => ('─', '━'),
+                [North, East] => ('└', '┗'),
+                [North, West] => ('┘', '┛'),
+                [South, West] => ('┐', '┓'),
+                [South, East] => ('┌', '┏'),
+                _ => ('?', '?'),
+            },
+        };
+        if walked {
+            bold
+        } else {
+            thin
+        }
+    }
+}
+
+fn find_loop(map: &Map) -> HashSet<Point> {
+    use Direction::*;
+    for dir in [North, South, West, East] {
+        let mut walk = Walk::new(map, dir);
+        // Ends with the start.
+        let loop_tiles: HashSet<Point> = walk.by_ref().collect();
+        if walk.back {
+            return loop_tiles;
+        }
+    }
+    HashSet::from([map.origin])
+}
+
+/// The tiles that aren't part of the loop and can be reached from outside
+/// of the map, squeezing between pipes.
+fn outside_tiles(map: &Map, loop_tiles: &HashSet<Point>) -> HashSet<Point> {
+    profile::scope!("day10 flood fill");
+    let mut marked = HashSet::new();
+    discover_neighbours(Point::new(-1, -1), map, &mut marked, loop_tiles);
+    let outside = non_loop_tiles(&marked, loop_tiles, map);
+    debug!(
+        loop_tiles = loop_tiles.len(),
+        outside = outside.len(),
+        "flood filled the outside"
+    );
+    outside
+}
+
+/// Marks the corners between tiles reachable from `start` without crossing
+/// the loop. Keeps its own stack, big maps would overflow the real one.
+fn discover_neighbours(
+    start: Point,
+    map: &Map,
+    marked: &mut HashSet<Point>,
+    loop_tiles: &HashSet<Point>,
+) {
+    use Direction::*;
+    let mut todo = vec![start];
+    while let Some(node) = todo.pop() {
+        if !marked.insert(node) {
+            continue;
+        }
+        for dir in [North, South, West, East] {
+            let (from, dir_cross) = match dir {
+                North => (node, East),
+                South => (node + South, East),
+                East => (node + East, South),
+                West => (node, South),
+            };
+
+            if node.x >= -1
+                && node.y >= -1
+                && node.x <= map.grid.width() as i64
+                && node.y <= map.grid.height() as i64
+            {
+                if loop_tiles.contains(&from)
+                    && loop_tiles.contains(&(from + dir_cross))
+                    && map.walk(from, dir_cross).is_some()
+                {
+                    continue;
+                }
+
+                todo.push(node + dir);
+            }
+        }
+    }
+}
+
+fn non_loop_tiles(
+    marked: &HashSet<Point>,
+    loop_tiles: &HashSet<Point>,
+    map: &Map,
+) -> HashSet<Point> {
+    let mut found_tiles = HashSet::new();
+    for knot in marked {
+        for x in 0..=1 {
+            for y in 0..=1 {
+                let to_check = Point::new(knot.x + x, knot.y + y);
+                if map.get(to_check).is_some() && !loop_tiles.contains(&to_check) {
+                    found_tiles.insert(to_check);
+                }
+            }
+        }
+    }
+    found_tiles
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::ops::ControlFlow;
+
+    use super::*;
+    use crate::common::grid::Coord;
+
+    #[test]
+    pub fn loop_in_the_corner() {
+        assert_eq!(Day10::solve_part2("S-7\n|.|\nL-J").unwrap(), 1);
+    }
+
+    #[test]
+    pub fn large_generated_loop() {
+        let input = crate::gen::get(2023, 10).unwrap().generate(1, 600);
+        let map = Day10::parse(&input).unwrap();
+        assert_eq!(Day10::part1(&map).unwrap(), Day10::part2(&map).unwrap() + 3);
+    }
+
+    #[test]
+    pub fn renders_inside() {
+        let map = Day10::parse(".....\n.S-7|\n.|.|.\n.L-J.").unwrap();
+        let picture = Day10::render(&map).unwrap().unwrap();
+
+        use Shade::*;
+        assert_eq!(picture.cells().row(0), [Outside; 5]);
+        assert_eq!(
+            picture.cells().row(1),
+            [Outside, Marked, Marked, Marked, Outside]
+        );
+        assert_eq!(
+            picture.cells().row(2),
+            [Outside, Marked, Inside, Marked, Outside]
+        );
+    }
+
+    #[test]
+    pub fn animates_the_loop() {
+        let map = Day10::parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap();
+        let mut frames = Vec::new();
+        let animated = Day10::animate(&map, &mut |frame| {
+            frames.push(frame);
+            ControlFlow::Continue(())
+        });
+        assert!(animated.unwrap());
+
+        // North leads nowhere, south goes around.
+        assert_eq!(frames[0].status, "step 0, no way North");
+        assert_eq!(frames[1].highlight, Some(Coord::new(1, 1)));
+        assert_eq!(frames[2].highlight, Some(Coord::new(2, 1)));
+        assert_eq!(frames[3].lines[1..4], [" S─┐ ", " ┃ │ ", " └─┘ "]);
+        let last = frames.last().unwrap();
+        assert_eq!(frames.len(), 10);
+        assert_eq!(last.lines[1..4], [" S━┓ ", " ┃ ┃ ", " ┗━┛ "]);
+        assert_eq!(
+            last.status,
+            "step 8, back at the start, the farthest tile is 4 steps away"
+        );
+    }
+
+    #[test]
+    pub fn invalid_tile() {
+        let err = Day10::parse("7-F7-\n.FJ|7\nSJxL7").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.message, "unexpected character 'x'");
+    }
+}
@@ -0,0 +1,272 @@
+use anyhow::{anyhow, bail};
+use itertools::Itertools;
+use tracing::{debug, trace};
+
+use crate::{
+    common::{
+        geom::{Point, Vector},
+        grid::{Coord, Grid},
+        ParseError,
+    },
+    profile,
+    render::{Picture, Shade},
+    solution::{Params, Part, Solution},
+};
+
+const DAY: u8 = 11;
+
+type Number = u64;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SpaceObject {
+    Galaxy,
+    Empty,
+}
+
+impl TryFrom<char> for SpaceObject {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::Galaxy),
+            '.' => Ok(Self::Empty),
+            c => Err(format!("unknown character {c:?}")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StarMap {
+    grid: Grid<SpaceObject>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>,
+}
+
+impl TryFrom<&str> for StarMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<StarMap, ParseError> {
+        let mut res = StarMap {
+            grid: Grid::parse(DAY, input)?,
+            empty_rows: Vec::new(),
+            empty_cols: Vec::new(),
+        };
+
+        for (idx, mut col) in res.grid.cols().enumerate() {
+            if col.all(|o| o == &SpaceObject::Empty) {
+                res.empty_cols.push(idx);
+            }
+        }
+
+        for (idx, row) in res.grid.rows().enumerate() {
+            if row.iter().all(|o| o == &SpaceObject::Empty) {
+                res.empty_rows.push(idx);
+            }
+        }
+
+        debug!(
+            empty_rows = ?res.empty_rows,
+            empty_cols = ?res.empty_cols,
+            "found empty space"
+        );
+
+        Ok(res)
+    }
+}
+
+impl StarMap {
+    /// The map with every empty row and column repeated `factor` times.
+    fn picture(&self, factor: usize) -> Picture {
+        let grow = |len: usize, empty: &[usize]| -> Vec<usize> {
+            (0..len)
+                .flat_map(|idx| {
+                    let times = if empty.contains(&idx) { factor } else { 1 };
+                    std::iter::repeat_n(idx, times)
+                })
+                .collect()
+        };
+        let rows = grow(self.grid.height(), &self.empty_rows);
+        let cols = grow(self.grid.width(), &self.empty_cols);
+
+        let mut shades = Vec::with_capacity(rows.len() * cols.len());
+        for &row in &rows {
+            for &col in &cols {
+                shades.push(if self.grid[Coord::new(row, col)] == SpaceObject::Galaxy {
+                    Shade::Marked
+                } else if self.empty_rows.contains(&row) || self.empty_cols.contains(&col) {
+                    Shade::Plain
+                } else {
+                    Shade::Background
+                });
+            }
+        }
+        Picture::new(Grid::new(cols.len(), shades))
+    }
+
+    /// Galaxy positions after every empty row and column grew to `factor`
+    /// times its size. `None` when they don't fit in an `i64`.
+    pub fn get_galaxies(&self, factor: usize) -> Option<Vec<Point>> {
+        let growth = |empty: &[usize], idx: usize| -> Option<i64> {
+            let growth = empty
+                .iter()
+                .filter(|&&e| e <= idx)
+                .count()
+                .checked_mul(factor - 1)?;
+            i64::try_from(growth).ok()
+        };
+
+        let mut res = Vec::new();
+        for (coord, &object) in self.grid.iter() {
+            if object == SpaceObject::Galaxy {
+                let offset = Vector::new(
+                    growth(&self.empty_cols, coord.col)?,
+                    growth(&self.empty_rows, coord.row)?,
+                );
+                let pos = Point::from(coord);
+                let pos = Point::new(pos.x.checked_add(offset.x)?, pos.y.checked_add(offset.y)?);
+                trace!(?coord, ?offset, ?pos, "galaxy moved");
+                res.push(pos);
+            }
+        }
+
+        Some(res)
+    }
+
+    /// The sum of the distances between every pair of galaxies.
+    pub fn get_distances(&self, factor: usize) -> anyhow::Result<Number> {
+        let too_big = || anyhow!("expanding {factor} times makes the distances too big");
+        self.get_galaxies(factor)
+            .ok_or_else(too_big)?
+            .into_iter()
+            .tuple_combinations()
+            .try_fold(0, |sum: Number, (from, to)| {
+                profile::progress!("day11 pair");
+                sum.checked_add(from.manhattan(to))
+            })
+            .ok_or_else(too_big)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    type Input<'a> = StarMap;
+    type Answer = Number;
+
+    fn parse(input: &str) -> Result<StarMap, ParseError> {
+        StarMap::try_from(input)
+    }
+
+    fn part1(input: &StarMap) -> anyhow::Result<Number> {
+        input.get_distances(2)
+    }
+
+    fn part2(input: &StarMap) -> anyhow::Result<Number> {
+        input.get_distances(1_000_000)
+    }
+
+    /// `factor` replaces how many times bigger the empty rows and columns
+    /// grow, 2 in part 1 and a million in part 2.
+    fn part_with_params(input: &StarMap, part: Part, params: &Params) -> anyhow::Result<Number> {
+        let mut factor = match part {
+            Part::One => 2,
+            Part::Two => 1_000_000,
+        };
+        for (name, &value) in params {
+            match name.as_str() {
+                "factor" => {
+                    factor = usize::try_from(value)
+                        .ok()
+                        .filter(|&factor| factor >= 1)
+                        .ok_or_else(|| anyhow!("invalid factor {value}"))?
+                }
+                name => bail!("day {DAY} has no parameter `{name}`"),
+            }
+        }
+        input.get_distances(factor)
+    }
+
+    /// The map before and after expanding as in part 1, side by side, with
+    /// galaxies marked and the empty rows and columns plain.
+    fn render(input: &StarMap) -> anyhow::Result<Option<Picture>> {
+        Ok(Some(input.picture(1).beside(input.picture(2))))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    pub fn renders_expansion() {
+        let map = Day11::parse("#..\n...\n..#").unwrap();
+        let picture = Day11::render(&map).unwrap().unwrap();
+
+        use Shade::*;
+        assert_eq!((picture.cells().width(), picture.cells().height()), (8, 4));
+        assert_eq!(
+            picture.cells().row(0),
+            [Marked, Plain, Background, Background, Marked, Plain, Plain, Background]
+        );
+        assert_eq!(picture.cells().row(2)[4..], [Plain; 4]);
+        assert_eq!(
+            picture.cells().row(3),
+            [Background, Background, Background, Background, Background, Plain, Plain, Marked]
+        );
+    }
+
+    #[test]
+    pub fn factor_too_big() {
+        let map = Day11::parse("#..\n...\n..#").unwrap();
+        let factor = |n| Params::from([("factor".to_string(), n)]);
+        assert_eq!(
+            Day11::part_with_params(&map, Part::Two, &factor(10)).unwrap(),
+            22
+        );
+        let err = Day11::part_with_params(&map, Part::Two, &factor(i64::MAX)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("expanding {} times makes the distances too big", i64::MAX)
+        );
+        let corners = Day11::parse("#.#\n...\n#.#").unwrap();
+        assert!(Day11::part_with_params(&corners, Part::Two, &factor(1 << 62)).is_err());
+    }
+
+    #[test]
+    pub fn ragged_rows() {
+        let err = StarMap::try_from("...#..\n.....\n#.....").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected a row of 6 cells");
+    }
+
+    fn render(rows: &[Vec<bool>]) -> String {
+        rows.iter()
+            .map(|row| row.iter().map(|&g| if g { '#' } else { '.' }).collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn transposing(
+            rows in (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+                prop::collection::vec(prop::collection::vec(prop::bool::weighted(0.2), width), height)
+            }),
+        ) {
+            let transposed: Vec<Vec<bool>> = (0..rows[0].len())
+                .map(|col| rows.iter().map(|row| row[col]).collect())
+                .collect();
+            let map = StarMap::try_from(render(&rows).as_str()).unwrap();
+            let transposed = StarMap::try_from(render(&transposed).as_str()).unwrap();
+            for factor in [2, 10, 1_000_000] {
+                prop_assert_eq!(map.get_distances(factor).unwrap(), transposed.get_distances(factor).unwrap());
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    cmp::max,
+    fmt::{self, Display},
+};
+
+use nom::{
+    character::complete::{alpha1, char, space0, space1},
+    multi::separated_list1,
+    sequence::{delimited, separated_pair},
+    Parser,
+};
+use nom_supreme::ParserExt;
+
+use crate::{
+    common::{
+        parse::{self, label, lines, number, Res},
+        ParseError,
+    },
+    explain::ExplainStep,
+    solution::{Part, Solution},
+};
+
+const DAY: u8 = 2;
+
+/// The red, green and blue cubes in the bag for part 1.
+const MAX: (u32, u32, u32) = (12, 13, 14);
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Round {
+    reds: u32,
+    greens: u32,
+    blues: u32,
+}
+
+impl Round {
+    pub fn possible(&self, maximums: (u32, u32, u32)) -> bool {
+        self.reds <= maximums.0 && self.greens <= maximums.1 && self.blues <= maximums.2
+    }
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} red, {} green, {} blue",
+            self.reds, self.greens, self.blues
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    /// The fewest cubes of each color that make every round possible.
+    fn minimum(&self) -> Round {
+        self.rounds.iter().fold(Round::default(), |acc, x| Round {
+            reds: max(acc.reds, x.reds),
+            greens: max(acc.greens, x.greens),
+            blues: max(acc.blues, x.blues),
+        })
+    }
+}
+
+fn color(input: &str) -> Res<'_, &str> {
+    alpha1
+        .map_res_cut(|color| match color {
+            "red" | "green" | "blue" => Ok(color),
+            _ => Err(format!("unknown color '{color}'")),
+        })
+        .parse(input)
+}
+
+fn round(input: &str) -> Res<'_, Round> {
+    separated_list1(
+        char(',').terminated(space0),
+        separated_pair(number::<u32>, space1, color),
+    )
+    .map(|pulls| {
+        let mut res = Round::default();
+        for (num, color) in pulls {
+            match color {
+                "red" => res.reds = num,
+                "green" => res.greens = num,
+                _ => res.blues = num,
+            }
+        }
+        res
+    })
+    .context("`<count> <color>, ...`")
+    .parse(input)
+}
+
+fn game(input: &str) -> Res<'_, Game> {
+    label("Game")
+        .context("`Game <id>:`")
+        .and(separated_list1(delimited(space0, char(';'), space0), round))
+        .map(|(id, rounds)| Game { id, rounds })
+        .parse(input)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input<'a> = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse::finish(DAY, input, lines(game))
+    }
+
+    fn part1(input: &Vec<Game>) -> anyhow::Result<u32> {
+        Ok(input
+            .iter()
+            .map(|g| {
+                if g.rounds.iter().any(|r| !r.possible(MAX)) {
+                    0
+                } else {
+                    g.id
+                }
+            })
+            .reduce(|a, b| a + b)
+            .unwrap())
+    }
+
+    fn part2(input: &Vec<Game>) -> anyhow::Result<u32> {
+        Ok(input
+            .iter()
+            .map(|g| {
+                let res = g.minimum();
+                res.reds * res.greens * res.blues
+            })
+            .reduce(|a, b| a + b)
+            .unwrap())
+    }
+
+    fn explain(input: &Vec<Game>, part: Part) -> anyhow::Result<Vec<ExplainStep>> {
+        Ok(input
+            .iter()
+            .map(|g| {
+                let detail = match part {
+                    Part::One => match g.rounds.iter().position(|r| !r.possible(MAX)) {
+                        Some(idx) => {
+                            format!("impossible, round {} draws {}", idx + 1, g.rounds[idx])
+                        }
+                        None => "possible".to_string(),
+                    },
+                    Part::Two => {
+                        let res = g.minimum();
+                        format!(
+                            "at least {res}, power {}",
+                            res.reds * res.greens * res.blues
+                        )
+                    }
+                };
+                ExplainStep::new(format!("Game {}", g.id), detail)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn convert_input() {
+        const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+
+        let res = Day2::parse(INPUT).unwrap();
+        let correct_res = vec![
+            Game {
+                id: 1,
+                rounds: vec![
+                    Round {
+                        blues: 3,
+                        reds: 4,
+                        greens: 0,
+                    },
+                    Round {
+                        reds: 1,
+                        blues: 6,
+                        greens: 2,
+                    },
+                    Round {
+                        greens: 2,
+                        ..Default::default()
+                    },
+                ],
+            },
+            Game {
+                id: 2,
+                rounds: vec![
+                    Round {
+                        blues: 1,
+                        reds: 0,
+                        greens: 2,
+                    },
+                    Round {
+                        reds: 1,
+                        blues: 4,
+                        greens: 3,
+                    },
+                    Round {
+                        greens: 1,
+                        blues: 1,
+                        ..Default::default()
+                    },
+                ],
+            },
+        ];
+        assert_eq!(res, correct_res);
+    }
+
+    #[test]
+    fn invalid_color() {
+        let err = Day2::parse("Game 1: 3 blue\nGame 2: 4 purple, 1 red").unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (2, 2, 11));
+        assert_eq!(err.message, "unknown color 'purple'");
+    }
+
+    #[test]
+    fn explains_games() {
+        const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let games = Day2::parse(EXAMPLE).unwrap();
+
+        let steps = Day2::explain(&games, Part::One).unwrap();
+        assert_eq!(steps[0].to_string(), "Game 1: possible");
+        assert_eq!(
+            steps[1].to_string(),
+            "Game 3: impossible, round 1 draws 20 red, 8 green, 6 blue"
+        );
+        let steps = Day2::explain(&games, Part::Two).unwrap();
+        assert_eq!(
+            steps[0].to_string(),
+            "Game 1: at least 4 red, 2 green, 6 blue, power 48"
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    common::{
+        grid::{Coord, Grid},
+        ParseError,
+    },
+    render::{Picture, Shade},
+    solution::Solution,
+};
+
+const DAY: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Digit(u8),
+    Symbol(char),
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            c if c.is_ascii_digit() => Ok(Self::Digit(c as u8 - b'0')),
+            c if c.is_ascii_graphic() => Ok(Self::Symbol(c)),
+            c => Err(format!("unexpected character {c:?}")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    cells: Grid<Cell>,
+    numbers: Vec<u32>,
+    /// The index into `numbers` of the number covering each cell.
+    number_at: Grid<Option<usize>>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input<'a> = Schematic;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        let cells = Grid::<Cell>::parse(DAY, input)?;
+        let mut numbers = Vec::new();
+        let mut number_at = Grid::new(cells.width(), vec![None; cells.len()]);
+
+        for (row, cells_row) in cells.rows().enumerate() {
+            let mut col = 0;
+            while col < cells_row.len() {
+                let start = col;
+                let mut value = Some(0u32);
+                while let Some(&Cell::Digit(digit)) = cells_row.get(col) {
+                    value = value.and_then(|v| v.checked_mul(10)?.checked_add(digit.into()));
+                    number_at[Coord::new(row, col)] = Some(numbers.len());
+                    col += 1;
+                }
+                if col == start {
+                    col += 1;
+                    continue;
+                }
+
+                let Some(value) = value else {
+                    let line = input
+                        .lines()
+                        .map(str::trim)
+                        .filter(|l| !l.is_empty())
+                        .nth(row)
+                        .unwrap();
+                    return Err(ParseError::at(
+                        DAY,
+                        input,
+                        &line[start..col],
+                        "part number out of range",
+                    ));
+                };
+                numbers.push(value);
+            }
+        }
+
+        Ok(Schematic {
+            cells,
+            numbers,
+            number_at,
+        })
+    }
+
+    fn part1(input: &Schematic) -> anyhow::Result<u32> {
+        Ok(input
+            .part_numbers()
+            .into_iter()
+            .map(|id| input.numbers[id])
+            .sum())
+    }
+
+    fn part2(input: &Schematic) -> anyhow::Result<u32> {
+        Ok(input
+            .gears()
+            .into_iter()
+            .map(|(_, ids)| ids.into_iter().map(|id| input.numbers[id]).product::<u32>())
+            .sum())
+    }
+
+    /// Part numbers are marked, gears highlighted and everything else that
+    /// isn't a `.` plain.
+    fn render(input: &Schematic) -> anyhow::Result<Option<Picture>> {
+        let part_numbers = input.part_numbers();
+        let gears: BTreeSet<_> = input.gears().into_iter().map(|(coord, _)| coord).collect();
+        let shades = input
+            .cells
+            .iter()
+            .map(|(coord, cell)| match cell {
+                Cell::Empty => Shade::Background,
+                Cell::Digit(_) if part_numbers.contains(&input.number_at[coord].unwrap()) => {
+                    Shade::Marked
+                }
+                Cell::Symbol(_) if gears.contains(&coord) => Shade::Highlight,
+                _ => Shade::Plain,
+            })
+            .collect();
+        Ok(Some(Picture::new(Grid::new(input.cells.width(), shades))))
+    }
+}
+
+impl Schematic {
+    /// The numbers adjacent to a symbol.
+    fn part_numbers(&self) -> BTreeSet<usize> {
+        let mut adjacent = BTreeSet::new();
+        for (coord, cell) in self.cells.iter() {
+            if let Cell::Symbol(_) = cell {
+                adjacent.extend(self.adjacent_numbers(coord));
+            }
+        }
+        adjacent
+    }
+
+    /// Every `*` adjacent to exactly two numbers, with those numbers.
+    fn gears(&self) -> Vec<(Coord, [usize; 2])> {
+        self.cells
+            .iter()
+            .filter(|(_, cell)| **cell == Cell::Symbol('*'))
+            .filter_map(|(coord, _)| {
+                let adjacent: Vec<_> = self.adjacent_numbers(coord).into_iter().collect();
+                Some((coord, adjacent.try_into().ok()?))
+            })
+            .collect()
+    }
+
+    fn adjacent_numbers(&self, coord: Coord) -> BTreeSet<usize> {
+        self.cells
+            .neighbours8(coord)
+            .filter_map(|neighbour| self.number_at[neighbour])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_character() {
+        let err = Day3::parse("467..114..\n...*..\t...").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+    }
+
+    #[test]
+    fn renders_gears() {
+        let schematic = Day3::parse("467..114..\n...*......\n..35...633").unwrap();
+        let picture = Day3::render(&schematic).unwrap().unwrap();
+
+        use Shade::*;
+        assert_eq!(
+            picture.cells().row(0),
+            [
+                Marked, Marked, Marked, Background, Background, Plain, Plain, Plain, Background,
+                Background
+            ]
+        );
+        assert_eq!(picture.cells().row(1)[3], Highlight);
+        assert_eq!(picture.cells().row(2)[7..], [Plain, Plain, Plain]);
+    }
+}
@@ -0,0 +1,155 @@
+use nom::{
+    character::complete::{char, space0},
+    sequence::{delimited, separated_pair},
+    Parser,
+};
+use nom_supreme::ParserExt;
+
+use crate::{
+    common::{
+        parse::{self, label, lines, numbers, Res},
+        ParseError,
+    },
+    explain::ExplainStep,
+    solution::{Part, Solution},
+};
+
+const DAY: u8 = 4;
+
+#[derive(Debug, Default)]
+pub struct Card {
+    winning: Vec<u32>,
+    actual: Vec<u32>,
+}
+
+impl Card {
+    /// How many of the numbers on the card are winning numbers.
+    fn matches(&self) -> usize {
+        self.actual
+            .iter()
+            .filter(|num| self.winning.contains(num))
+            .count()
+    }
+
+    fn points(&self) -> u32 {
+        match self.matches() {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
+    }
+}
+
+fn card(input: &str) -> Res<'_, Card> {
+    label("Card")
+        .context("`Card <id>:`")
+        .precedes(separated_pair(
+            numbers,
+            delimited(space0, char('|'), space0),
+            numbers,
+        ))
+        .map(|(winning, actual)| Card { winning, actual })
+        .parse(input)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input<'a> = Vec<Card>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse::finish(DAY, input, lines(card))
+    }
+
+    fn part1(input: &Vec<Card>) -> anyhow::Result<u32> {
+        Ok(input.iter().map(Card::points).sum())
+    }
+
+    fn part2(input: &Vec<Card>) -> anyhow::Result<u32> {
+        Ok(copies(input).into_iter().reduce(|acc, x| acc + x).unwrap())
+    }
+
+    fn explain(input: &Vec<Card>, part: Part) -> anyhow::Result<Vec<ExplainStep>> {
+        let copies = copies(input);
+        Ok(input
+            .iter()
+            .enumerate()
+            .map(|(idx, card)| {
+                let matches = card.matches();
+                let detail = match part {
+                    Part::One => format!("{matches} matches, {} points", card.points()),
+                    Part::Two if matches == 0 || idx + 1 == input.len() => {
+                        format!("{matches} matches, {} copies", copies[idx])
+                    }
+                    Part::Two => {
+                        let (first, last) = (idx + 2, (idx + 1 + matches).min(input.len()));
+                        let won = if first == last {
+                            format!("card {first}")
+                        } else {
+                            format!("cards {first} to {last}")
+                        };
+                        format!(
+                            "{matches} matches, {} copies, each winning a copy of {won}",
+                            copies[idx]
+                        )
+                    }
+                };
+                ExplainStep::new(format!("Card {}", idx + 1), detail)
+            })
+            .collect())
+    }
+}
+
+/// How many of every card there are once all the won copies are scratched.
+fn copies(input: &[Card]) -> Vec<u32> {
+    let mut card_pile = vec![1; input.len()];
+    for (idx, card) in input.iter().enumerate() {
+        for i in idx + 1..=idx + card.matches() {
+            if i >= card_pile.len() {
+                break;
+            }
+
+            card_pile[i] += card_pile[idx];
+        }
+    }
+    card_pile
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_number() {
+        let err = Day4::parse("Card 1: 41 48 | 83 86\nCard 2: 13 3x | 61").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 12));
+        assert_eq!(err.message, "invalid number '3x'");
+    }
+
+    #[test]
+    fn explains_copies() {
+        let cards = Day4::parse(
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+        )
+        .unwrap();
+
+        let steps = Day4::explain(&cards, Part::One).unwrap();
+        assert_eq!(steps[0].to_string(), "Card 1: 4 matches, 8 points");
+        let steps = Day4::explain(&cards, Part::Two).unwrap();
+        assert_eq!(
+            steps[0].to_string(),
+            "Card 1: 4 matches, 1 copies, each winning a copy of cards 2 to 3"
+        );
+        assert_eq!(
+            steps[1].to_string(),
+            "Card 2: 2 matches, 2 copies, each winning a copy of card 3"
+        );
+        assert_eq!(steps[2].to_string(), "Card 3: 0 matches, 4 copies");
+    }
+}
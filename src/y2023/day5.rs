@@ -0,0 +1,341 @@
+use anyhow::anyhow;
+use nom::{
+    character::complete::{alpha1, space1},
+    sequence::{separated_pair, terminated, tuple},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use rayon::prelude::*;
+use std::{collections::HashMap, ops::Range};
+use tracing::debug;
+
+use crate::{
+    common::{
+        parse::{self, header, line, number, numbers, sections, Res},
+        ParseError,
+    },
+    profile,
+    progress::{Progress, Silent},
+    solution::Solution,
+};
+
+const DAY: u8 = 5;
+
+const TRANSLATION_CHAIN: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+#[derive(Debug, Default)]
+pub struct ResourceMap {
+    source_ranges: Vec<Range<u64>>,
+    destination_ranges: Vec<Range<u64>>,
+}
+
+impl ResourceMap {
+    fn map_to_destination(&self, source: u64) -> u64 {
+        for (idx, range) in self.source_ranges.iter().enumerate() {
+            if range.contains(&source) {
+                return self.destination_ranges[idx].start + (source - range.start);
+            }
+        }
+        source
+    }
+
+    fn insert_range(&mut self, source_start: u64, dest_start: u64, len: u64) {
+        self.source_ranges.push(source_start..source_start + len);
+        self.destination_ranges.push(dest_start..dest_start + len);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Maps {
+    maps: HashMap<(String, String), ResourceMap>,
+    seeds: Vec<u64>,
+}
+
+fn map_header(input: &str) -> Res<'_, (&str, &str)> {
+    terminated(separated_pair(alpha1, tag("-to-"), alpha1), tag(" map:"))
+        .context("`<a>-to-<b> map:` header")
+        .parse(input)
+}
+
+/// `<destination> <source> <length>`
+fn range(input: &str) -> Res<'_, (u64, u64, u64)> {
+    tuple((number, space1.precedes(number), space1.precedes(number)))
+        .map_res_cut(|(destination, source, len): (u64, u64, u64)| {
+            match (destination.checked_add(len), source.checked_add(len)) {
+                (Some(_), Some(_)) => Ok((destination, source, len)),
+                _ => Err("range out of bounds".to_string()),
+            }
+        })
+        .context("`<destination> <source> <length>` range")
+        .parse(input)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input<'a> = Maps;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Maps, ParseError> {
+        let seeds = line(header("seeds").precedes(numbers.with_recognized()));
+        let ((seeds_source, seeds), sections) =
+            parse::finish(DAY, input, seeds.and(sections(map_header, range)))?;
+
+        let mut res = Maps {
+            seeds,
+            ..Default::default()
+        };
+        for ((source, destination), ranges) in sections {
+            let entry = res
+                .maps
+                .entry((source.to_string(), destination.to_string()))
+                .or_default();
+            for (destination, source, range_len) in ranges {
+                entry.insert_range(source, destination, range_len);
+            }
+        }
+
+        for mapping in TRANSLATION_CHAIN.windows(2) {
+            let key = (mapping[0].to_string(), mapping[1].to_string());
+            if !res.maps.contains_key(&key) {
+                return Err(ParseError::at_end(
+                    DAY,
+                    input,
+                    format!("missing `{}-to-{} map:` section", key.0, key.1),
+                ));
+            }
+        }
+        if !res.seeds.len().is_multiple_of(2) {
+            return Err(ParseError::at(
+                DAY,
+                input,
+                seeds_source,
+                "expected an even number of seeds",
+            ));
+        }
+
+        Ok(res)
+    }
+
+    fn part1(input: &Maps) -> anyhow::Result<u64> {
+        let mut res = u64::MAX;
+        for seed in input.seeds.iter() {
+            profile::progress!("day5 seed");
+            let mut latest = *seed;
+            for mapping in TRANSLATION_CHAIN.windows(2) {
+                latest = input
+                    .maps
+                    .get(&(mapping[0].to_string(), mapping[1].to_string()))
+                    .unwrap()
+                    .map_to_destination(latest);
+            }
+            res = res.min(latest);
+        }
+
+        Ok(res)
+    }
+
+    fn part2(input: &Maps) -> anyhow::Result<u64> {
+        Self::part2_with_progress(input, &Silent)
+    }
+
+    fn part2_with_progress(input: &Maps, progress: &dyn Progress) -> anyhow::Result<u64> {
+        // Seeds are handed out in blocks, so reporting costs nothing next to
+        // mapping them.
+        const BLOCK: u64 = 1 << 20;
+
+        let ranges = input
+            .seeds
+            .chunks(2)
+            .enumerate()
+            .map(|(idx, range)| {
+                let end = range[0].checked_add(range[1]).ok_or_else(|| {
+                    anyhow!(
+                        "seed range {} ends past the largest seed {}",
+                        idx + 1,
+                        u64::MAX
+                    )
+                })?;
+                Ok(range[0]..end)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        // Only a hint, so it may as well stop at the largest number there is.
+        progress.start(
+            ranges
+                .iter()
+                .map(|range| range.end - range.start)
+                .fold(0, u64::saturating_add),
+        );
+        let mut res = u64::MAX;
+        for (idx, range) in ranges.iter().enumerate() {
+            profile::scope!("day5 seed range");
+            progress.message(&format!("seed range {}/{}", idx + 1, ranges.len()));
+            debug!(?range, "mapping seed range");
+            for start in range.clone().step_by(BLOCK as usize) {
+                let block = start..range.end.min(start.saturating_add(BLOCK));
+                let len = block.end - block.start;
+                res = res.min(
+                    block
+                        .into_par_iter()
+                        .map(|seed| {
+                            profile::progress!("day5 seed");
+                            let mut latest = seed;
+                            for mapping in TRANSLATION_CHAIN.windows(2) {
+                                latest = input
+                                    .maps
+                                    .get(&(mapping[0].to_string(), mapping[1].to_string()))
+                                    .unwrap()
+                                    .map_to_destination(latest);
+                            }
+                            latest
+                        })
+                        .min()
+                        .unwrap(),
+                );
+                progress.advance(len);
+            }
+        }
+        progress.finish();
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[derive(Default)]
+    struct Counter {
+        total: AtomicU64,
+        done: AtomicU64,
+    }
+
+    impl Progress for Counter {
+        fn start(&self, total: u64) {
+            self.total.store(total, Ordering::Relaxed);
+        }
+
+        fn advance(&self, units: u64) {
+            self.done.fetch_add(units, Ordering::Relaxed);
+        }
+
+        fn message(&self, _message: &str) {}
+
+        fn finish(&self) {}
+    }
+
+    #[test]
+    fn reports_progress() {
+        let maps = Day5::parse(EXAMPLE).unwrap();
+        let progress = Counter::default();
+        assert_eq!(Day5::part2_with_progress(&maps, &progress).unwrap(), 46);
+        assert_eq!(progress.total.load(Ordering::Relaxed), 27);
+        assert_eq!(progress.done.load(Ordering::Relaxed), 27);
+    }
+
+    #[test]
+    fn seed_range_past_the_end() {
+        let input = EXAMPLE.replace("79 14 55 13", "79 14 1 18446744073709551615");
+        let err = Day5::solve_part2(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "seed range 2 ends past the largest seed 18446744073709551615"
+        );
+    }
+
+    #[test]
+    fn missing_map() {
+        let input = EXAMPLE.split("humidity-to-location").next().unwrap();
+        let err = Day5::parse(input).unwrap_err();
+        assert_eq!(err.message, "missing `humidity-to-location map:` section");
+    }
+
+    /// `lengths` laid out back to back on the source side, and in the order
+    /// of `order` on the destination side.
+    fn shuffled_map(lengths: &[u64], order: &[usize]) -> ResourceMap {
+        let mut destinations = vec![0; lengths.len()];
+        let mut destination = 0;
+        for &idx in order {
+            destinations[idx] = destination;
+            destination += lengths[idx];
+        }
+
+        let mut res = ResourceMap::default();
+        let mut source = 0;
+        for (len, destination) in lengths.iter().zip(destinations) {
+            res.insert_range(source, destination, *len);
+            source += len;
+        }
+        res
+    }
+
+    proptest! {
+        #[test]
+        fn injective(
+            (lengths, order) in prop::collection::vec(1..50u64, 1..8).prop_flat_map(|lengths| {
+                let order = Just((0..lengths.len()).collect::<Vec<_>>()).prop_shuffle();
+                (Just(lengths), order)
+            }),
+        ) {
+            let map = shuffled_map(&lengths, &order);
+            let domain = 0..lengths.iter().sum::<u64>() + 10;
+            let images: HashSet<u64> = domain.clone().map(|x| map.map_to_destination(x)).collect();
+            prop_assert_eq!(images.len() as u64, domain.end);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use nom::{character::complete::multispace0, Parser};
+use nom_supreme::ParserExt;
+
+use crate::{
+    common::{
+        parse::{self, header, line, numbers},
+        ParseError,
+    },
+    solution::Solution,
+};
+
+const DAY: u8 = 6;
+
+type Number = u64;
+
+#[derive(Debug)]
+pub struct Race {
+    time: Number,
+    distance: Number,
+}
+
+impl Race {
+    fn ways_to_win(&self) -> Number {
+        (1..self.time)
+            .map(|i| (self.time - i) * i)
+            .fold(0, |acc, x| if x > self.distance { acc + 1 } else { acc })
+    }
+}
+
+fn races_from_str(input: &str) -> Result<Vec<Race>, ParseError> {
+    let times = line(header("Time").precedes(numbers));
+    let distances =
+        multispace0.precedes(line(header("Distance").precedes(numbers)).with_recognized());
+    let (times, (distances_line, distances)) = parse::finish(
+        DAY,
+        input,
+        times
+            .context("`Time:` line")
+            .and(distances.context("`Distance:` line")),
+    )?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::at(
+            DAY,
+            input,
+            distances_line,
+            format!(
+                "expected {} distances, got {}",
+                times.len(),
+                distances.len()
+            ),
+        ));
+    }
+
+    Ok(times
+        .iter()
+        .zip(distances.iter())
+        .map(|(time, distance)| Race {
+            time: *time,
+            distance: *distance,
+        })
+        .collect())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input<'a> = Vec<Race>;
+    type Answer = Number;
+
+    fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+        races_from_str(input)
+    }
+
+    fn part1(input: &Vec<Race>) -> anyhow::Result<Number> {
+        Ok(input.iter().map(Race::ways_to_win).product())
+    }
+
+    /// The spaces between the numbers were bad kerning, so it's a single race.
+    fn part2(input: &Vec<Race>) -> anyhow::Result<Number> {
+        let race = Race {
+            time: input
+                .iter()
+                .map(|r| r.time.to_string())
+                .collect::<String>()
+                .parse()?,
+            distance: input
+                .iter()
+                .map(|r| r.distance.to_string())
+                .collect::<String>()
+                .parse()?,
+        };
+        Ok(race.ways_to_win())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_distance() {
+        let err = Day6::parse("Time:      7  15   30\nDistance:  9  40\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 3 distances, got 2");
+    }
+}
@@ -0,0 +1,390 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Display},
+    ops::Add,
+};
+
+use nom::{
+    character::complete::{satisfy, space1},
+    multi::many1,
+    sequence::separated_pair,
+    Parser,
+};
+use nom_supreme::ParserExt;
+
+use crate::{
+    common::{
+        parse::{self, lines, number, Res},
+        ParseError,
+    },
+    explain::ExplainStep,
+    solution::{Part, Solution},
+};
+
+const DAY: u8 = 7;
+
+type Number = u32;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Card {
+    value: u8,
+}
+
+impl TryFrom<char> for Card {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let value = match value {
+            '2'..='9' => value as u8 - b'0',
+            'T' => 10,
+            'J' => 11,
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            c => return Err(format!("unrecognized card '{c}'")),
+        };
+
+        Ok(Card { value })
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self.value {
+            10 => 'T',
+            11 => 'J',
+            12 => 'Q',
+            13 => 'K',
+            14 => 'A',
+            n => (b'0' + n) as char,
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl Card {
+    const JACK: Card = Card { value: 11 };
+
+    /// Played as jokers, jacks are the weakest card.
+    fn strength(self, jokers: bool) -> u8 {
+        if jokers && self == Self::JACK {
+            1
+        } else {
+            self.value
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum HandType {
+    FiveOfAKind(Card),
+    FourOfAKind { member: Card, rest: Card },
+    FullHouse { three: Card, two: Card },
+    ThreeOfAKind { member: Card, rest: [Card; 2] },
+    TwoPair { pairs: [Card; 2], rest: Card },
+    OnePair { pair: Card, rest: [Card; 3] },
+    HighCard([Card; 5]),
+}
+
+impl Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use HandType::*;
+        f.write_str(match self {
+            FiveOfAKind(_) => "five of a kind",
+            FourOfAKind { .. } => "four of a kind",
+            FullHouse { .. } => "full house",
+            ThreeOfAKind { .. } => "three of a kind",
+            TwoPair { .. } => "two pair",
+            OnePair { .. } => "one pair",
+            HighCard(_) => "high card",
+        })
+    }
+}
+
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use HandType::*;
+        let to_num = |t: &HandType| match *t {
+            FiveOfAKind(_) => 6,
+            FourOfAKind { .. } => 5,
+            FullHouse { .. } => 4,
+            ThreeOfAKind { .. } => 3,
+            TwoPair { .. } => 2,
+            OnePair { .. } => 1,
+            HighCard(_) => 0,
+        };
+        to_num(self).cmp(&to_num(other))
+    }
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<&Hand> for HandType {
+    fn from(value: &Hand) -> Self {
+        let mut card_map: HashMap<Card, u8> = HashMap::new();
+        for card in value.cards.iter() {
+            let entry = card_map.entry(*card).or_insert(0);
+            *entry = entry.add(1);
+        }
+
+        if value.jokers && card_map.len() > 1 {
+            if let Some(num_jokers) = card_map.remove(&Card::JACK) {
+                let most_of_in_hand = {
+                    let mut card_iter = card_map.iter();
+                    let mut most_cards = card_iter.next().unwrap();
+                    for (card, num) in card_iter {
+                        if num > most_cards.1 {
+                            most_cards = (card, num);
+                        }
+                    }
+                    *most_cards.0
+                };
+                let most_cards = card_map.get_mut(&most_of_in_hand).unwrap();
+                *most_cards += num_jokers;
+            }
+        }
+
+        if card_map.len() == 1 {
+            HandType::FiveOfAKind(*card_map.keys().next().unwrap())
+        } else if card_map.len() == 2 {
+            let mut card_iter = card_map.into_iter();
+            match card_iter.next().unwrap() {
+                (card, 1) => HandType::FourOfAKind {
+                    member: card_iter.next().unwrap().0,
+                    rest: card,
+                },
+                (card, 2) => HandType::FullHouse {
+                    three: card_iter.next().unwrap().0,
+                    two: card,
+                },
+                (card, 3) => HandType::FullHouse {
+                    three: card,
+                    two: card_iter.next().unwrap().0,
+                },
+                (card, 4) => HandType::FourOfAKind {
+                    member: card,
+                    rest: card_iter.next().unwrap().0,
+                },
+                _ => unreachable!(),
+            }
+        } else if card_map.len() == 3 {
+            let mut cards: Vec<(Card, u8)> = card_map.into_iter().collect();
+            cards.sort_by_key(|c| std::cmp::Reverse(c.1));
+            if cards[0].1 == 3 {
+                HandType::ThreeOfAKind {
+                    member: cards[0].0,
+                    rest: [cards[1].0, cards[2].0],
+                }
+            } else {
+                HandType::TwoPair {
+                    pairs: [cards[0].0, cards[1].0],
+                    rest: cards[2].0,
+                }
+            }
+        } else if card_map.len() == 4 {
+            let mut cards: Vec<(Card, u8)> = card_map.into_iter().collect();
+            cards.sort_by_key(|c| std::cmp::Reverse(c.1));
+            HandType::OnePair {
+                pair: cards[0].0,
+                rest: [cards[1].0, cards[2].0, cards[3].0],
+            }
+        } else {
+            let cards: Vec<Card> = card_map.into_keys().collect();
+            HandType::HighCard(cards.try_into().unwrap())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: Number,
+    jokers: bool,
+}
+
+impl Hand {
+    fn strengths(&self) -> [u8; 5] {
+        self.cards.map(|c| c.strength(self.jokers))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let (self_type, other_type) = (HandType::from(self), HandType::from(other));
+        self.jokers
+            .cmp(&other.jokers)
+            .then(self_type.cmp(&other_type))
+            .then_with(|| self.strengths().cmp(&other.strengths()))
+            .then(self.bid.cmp(&other.bid))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn cards(input: &str) -> Res<'_, [Card; 5]> {
+    many1(satisfy(|c| !c.is_whitespace()).map_res_cut(Card::try_from))
+        .map_res_cut(|cards: Vec<Card>| {
+            let len = cards.len();
+            cards
+                .try_into()
+                .map_err(|_| format!("expected 5 cards, got {len}"))
+        })
+        .parse(input)
+}
+
+fn hand(input: &str) -> Res<'_, Hand> {
+    separated_pair(cards, space1, number)
+        .map(|(cards, bid)| Hand {
+            cards,
+            bid,
+            jokers: false,
+        })
+        .context("`<cards> <bid>`")
+        .parse(input)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Input<'a> = Vec<Hand>;
+    type Answer = Number;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+        parse::finish(DAY, input, lines(hand))
+    }
+
+    fn part1(input: &Vec<Hand>) -> anyhow::Result<Number> {
+        Ok(total_winnings(input.to_vec()))
+    }
+
+    fn part2(input: &Vec<Hand>) -> anyhow::Result<Number> {
+        Ok(total_winnings(with_jokers(input)))
+    }
+
+    fn explain(input: &Vec<Hand>, part: Part) -> anyhow::Result<Vec<ExplainStep>> {
+        let mut hands = match part {
+            Part::One => input.to_vec(),
+            Part::Two => with_jokers(input),
+        };
+        hands.sort();
+
+        Ok(hands
+            .iter()
+            .enumerate()
+            .map(|(idx, hand)| {
+                let rank = idx as Number + 1;
+                let cards: String = hand.cards.iter().map(Card::to_string).collect();
+                ExplainStep::new(
+                    cards,
+                    format!(
+                        "{}, rank {rank}, wins {} * {rank} = {}",
+                        HandType::from(hand),
+                        hand.bid,
+                        hand.bid * rank
+                    ),
+                )
+            })
+            .collect())
+    }
+}
+
+fn with_jokers(hands: &[Hand]) -> Vec<Hand> {
+    hands
+        .iter()
+        .map(|hand| Hand {
+            jokers: true,
+            ..hand.clone()
+        })
+        .collect()
+}
+
+fn total_winnings(mut hands: Vec<Hand>) -> Number {
+    hands.sort();
+
+    let mut res = 0;
+    for (idx, hand) in hands.iter().enumerate() {
+        res += hand.bid * (idx as Number + 1);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn explains_ranks() {
+        let hands = Day7::parse("32T3K 765\nT55J5 684\nKTJJT 220").unwrap();
+
+        let steps: Vec<String> = Day7::explain(&hands, Part::One)
+            .unwrap()
+            .iter()
+            .map(ExplainStep::to_string)
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                "32T3K: one pair, rank 1, wins 765 * 1 = 765",
+                "KTJJT: two pair, rank 2, wins 220 * 2 = 440",
+                "T55J5: three of a kind, rank 3, wins 684 * 3 = 2052",
+            ]
+        );
+        let steps = Day7::explain(&hands, Part::Two).unwrap();
+        assert_eq!(
+            steps[2].to_string(),
+            "KTJJT: four of a kind, rank 3, wins 220 * 3 = 660"
+        );
+    }
+
+    #[test]
+    fn invalid_card() {
+        let err = Day7::parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.message, "unrecognized card 'X'");
+    }
+
+    fn any_hand() -> impl Strategy<Value = Hand> {
+        let card = prop::sample::select("23456789TJQKA".chars().collect::<Vec<_>>())
+            .prop_map(|c| Card::try_from(c).unwrap());
+        (prop::array::uniform5(card), 0..10 as Number, any::<bool>())
+            .prop_map(|(cards, bid, jokers)| Hand { cards, bid, jokers })
+    }
+
+    proptest! {
+        #[test]
+        fn total_order(a in any_hand(), b in any_hand(), c in any_hand()) {
+            prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+
+        #[test]
+        fn sorts_consistently(mut hands in prop::collection::vec(any_hand(), 1..20)) {
+            hands.sort();
+            for (idx, a) in hands.iter().enumerate() {
+                for b in &hands[idx..] {
+                    prop_assert!(a <= b);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use nom::{character::complete::satisfy, multi::many1, Parser};
+use nom_supreme::ParserExt;
+use std::{collections::HashMap, rc::Rc};
+
+use anyhow::{anyhow, bail};
+use tracing::debug;
+
+use crate::{
+    animate::{Frame, Show},
+    common::{
+        grid::Coord,
+        parse::{self, key_pair, line, lines, Res},
+        ParseError,
+    },
+    profile,
+    solution::Solution,
+};
+
+const DAY: u8 = 8;
+
+type Number = u64;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Instructions {
+    directions: Vec<Direction>,
+    current: usize,
+}
+
+impl Iterator for Instructions {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.directions[self.current];
+        self.current = (self.current + 1) % self.directions.len();
+
+        Some(res)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Node<'a> {
+    name: &'a str,
+    /// Indices into the nodes of the [`Map`].
+    children: [usize; 2],
+}
+
+impl<'a> Node<'a> {
+    #[inline(always)]
+    pub fn get(&self, direction: Direction) -> usize {
+        self.children[direction as usize]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map<'a> {
+    nodes: Rc<[Node<'a>]>,
+    current_node: usize,
+    instructions: Instructions,
+}
+
+impl<'a> Iterator for Map<'a> {
+    type Item = &'a str;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        profile::progress!("day8 step");
+        self.current_node = self.nodes[self.current_node].get(self.instructions.next().unwrap());
+        Some(self.nodes[self.current_node].name)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Map<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (directions, nodes) = parse::finish(
+            DAY,
+            value,
+            line(many1(direction))
+                .context("a line of `L`/`R` directions")
+                .and(lines(key_pair)),
+        )?;
+
+        // Numbered in order of appearance, a node defined twice keeps its
+        // last definition.
+        let mut index = HashMap::new();
+        for (name, _) in &nodes {
+            let next = index.len();
+            index.entry(*name).or_insert(next);
+        }
+        let mut res = vec![None; index.len()];
+        for (name, (left, right)) in nodes {
+            let mut children = [0; 2];
+            for (child, name) in children.iter_mut().zip([left, right]) {
+                *child = *index.get(name).ok_or_else(|| {
+                    ParseError::at(DAY, value, name, format!("unknown node '{name}'"))
+                })?;
+            }
+            res[index[name]] = Some(Node { name, children });
+        }
+
+        Ok(Map {
+            nodes: res.into_iter().map(Option::unwrap).collect(),
+            current_node: 0,
+            instructions: Instructions {
+                directions,
+                current: 0,
+            },
+        })
+    }
+}
+
+impl Map<'_> {
+    /// A copy of the map that starts walking from the node called `name`.
+    pub fn start_at(&self, name: &str) -> Option<Self> {
+        let mut res = self.clone();
+        res.current_node = self.nodes.iter().position(|node| node.name == name)?;
+        Some(res)
+    }
+}
+
+fn direction(input: &str) -> Res<'_, Direction> {
+    satisfy(|c| !c.is_whitespace())
+        .map_res_cut(|c| match c {
+            'R' => Ok(Direction::Right),
+            'L' => Ok(Direction::Left),
+            c => Err(format!("unexpected character {c:?} in directions")),
+        })
+        .parse(input)
+}
+
+fn vec_lcm(input: Vec<Number>) -> Number {
+    input.into_iter().fold(1, num::integer::lcm)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input<'a> = Map<'a>;
+    type Answer = Number;
+
+    fn parse(input: &str) -> Result<Map<'_>, ParseError> {
+        Map::try_from(input)
+    }
+
+    fn part1(input: &Map<'_>) -> anyhow::Result<Number> {
+        let map = input
+            .start_at("AAA")
+            .ok_or_else(|| anyhow!("there is no node called AAA"))?;
+        let mut res = 0;
+        for node in map {
+            res += 1;
+            if node == "ZZZ" {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn part2(input: &Map<'_>) -> anyhow::Result<Number> {
+        let routes: Vec<Number> = input
+            .nodes
+            .iter()
+            .filter(|node| node.name.ends_with('A'))
+            .map(|node| {
+                let mut res = 0;
+                for node in input.start_at(node.name).unwrap() {
+                    res += 1;
+                    if node.ends_with('Z') {
+                        break;
+                    }
+                }
+                debug!(start = node.name, steps = res, "ghost arrived");
+                res
+            })
+            .collect();
+
+        if routes.is_empty() {
+            bail!("there are no nodes ending in A");
+        }
+        Ok(vec_lcm(routes))
+    }
+
+    /// The walk from `AAA` to `ZZZ` of part 1. The instruction about to be
+    /// followed is highlighted and the child it leads to underlined.
+    fn animate(input: &Map<'_>, show: &mut Show<'_>) -> anyhow::Result<bool> {
+        let mut map = input
+            .start_at("AAA")
+            .ok_or_else(|| anyhow!("there is no node called AAA"))?;
+        let directions: String = map
+            .instructions
+            .directions
+            .iter()
+            .map(|direction| match direction {
+                Direction::Left => 'L',
+                Direction::Right => 'R',
+            })
+            .collect();
+
+        for step in 0.. {
+            let node = &map.nodes[map.current_node];
+            let current = map.instructions.current;
+            let frame = if step > 0 && node.name == "ZZZ" {
+                Frame {
+                    lines: vec![directions.clone(), String::new(), node.name.to_string()],
+                    highlight: None,
+                    status: format!("step {step}, arrived at ZZZ"),
+                }
+            } else {
+                let [left, right] = node.children.map(|child| map.nodes[child].name);
+                let direction = map.instructions.directions[current];
+                let (offset, len) = match direction {
+                    Direction::Left => (node.name.len() + 4, left.len()),
+                    Direction::Right => (node.name.len() + 6 + left.len(), right.len()),
+                };
+                Frame {
+                    lines: vec![
+                        directions.clone(),
+                        String::new(),
+                        format!("{} = ({left}, {right})", node.name),
+                        format!("{}{}", " ".repeat(offset), "^".repeat(len)),
+                    ],
+                    highlight: Some(Coord::new(0, current)),
+                    status: format!(
+                        "step {step}, instruction {}/{}: {direction:?}",
+                        current + 1,
+                        directions.len()
+                    ),
+                }
+            };
+            if show(frame).is_break() || (step > 0 && node.name == "ZZZ") {
+                break;
+            }
+            map.next();
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use super::*;
+
+    #[test]
+    pub fn animates_the_walk() {
+        let map = Day8::parse(
+            "RL
+
+AAA = (BBB, CCC)
+CCC = (ZZZ, GGG)
+BBB = (BBB, BBB)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+
+        let mut frames = Vec::new();
+        let animated = Day8::animate(&map, &mut |frame| {
+            frames.push(frame);
+            ControlFlow::Continue(())
+        });
+        assert!(animated.unwrap());
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            frames[0].lines[2..],
+            ["AAA = (BBB, CCC)", "            ^^^"]
+        );
+        assert_eq!(frames[1].lines[3], "       ^^^");
+        assert_eq!(frames[1].highlight, Some(Coord::new(0, 1)));
+        assert_eq!(frames[1].status, "step 1, instruction 2/2: Left");
+        assert_eq!(frames[2].status, "step 2, arrived at ZZZ");
+
+        let mut shown = 0;
+        Day8::animate(&map, &mut |_| {
+            shown += 1;
+            ControlFlow::Break(())
+        })
+        .unwrap();
+        assert_eq!(shown, 1);
+    }
+
+    #[test]
+    pub fn long_chain() {
+        let mut input = String::from("L\n\nAAA = (N1, N1)\n");
+        for idx in 1..100_000 {
+            input += &format!("N{idx} = (N{next}, N{next})\n", next = idx + 1);
+        }
+        input += "N100000 = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(Day8::solve_part1(&input).unwrap(), 100_001);
+    }
+
+    #[test]
+    pub fn unknown_node() {
+        let input = "LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let err = Day8::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 8));
+        assert_eq!(err.message, "unknown node 'BBB'");
+    }
+}
@@ -0,0 +1,218 @@
+use nom::Parser;
+
+use crate::{
+    common::{
+        parse::{self, lines, numbers},
+        ParseError,
+    },
+    explain::ExplainStep,
+    solution::{Part, Solution},
+};
+
+const DAY: u8 = 9;
+
+type Number = i64;
+
+#[derive(Debug, Default)]
+pub struct Sequence {
+    numbers: Vec<Number>,
+}
+
+impl Sequence {
+    fn derive(&self) -> Self {
+        Self {
+            numbers: self.numbers.windows(2).map(|a| a[1] - a[0]).collect(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.numbers.iter().any(|x| x != &0)
+    }
+
+    fn get_next(&self) -> Number {
+        if self.is_zero() {
+            0
+        } else {
+            self.numbers.last().unwrap() + self.derive().get_next()
+        }
+    }
+
+    fn get_previous(&self) -> Number {
+        if self.is_zero() {
+            0
+        } else {
+            self.numbers.first().unwrap() - self.derive().get_previous()
+        }
+    }
+
+    /// The sequence and its differences down to the first all zero row,
+    /// extended by one number at the end, or at the start if `backwards`.
+    fn pyramid(&self, backwards: bool) -> Vec<Vec<Number>> {
+        let mut rows = vec![self.numbers.clone()];
+        let mut sequence = self.derive();
+        loop {
+            rows.push(sequence.numbers.clone());
+            if sequence.is_zero() {
+                break;
+            }
+            sequence = sequence.derive();
+        }
+        if self.is_zero() {
+            rows.pop();
+        }
+
+        let mut below = 0;
+        for row in rows.iter_mut().rev() {
+            if backwards {
+                below = row.first().unwrap_or(&0) - below;
+                row.insert(0, below);
+            } else {
+                below += row.last().unwrap_or(&0);
+                row.push(below);
+            }
+        }
+        rows
+    }
+}
+
+/// Lays out `rows` so every difference sits between the two numbers it
+/// comes from.
+fn draw_pyramid(rows: &[Vec<Number>]) -> Vec<String> {
+    let widest = rows
+        .iter()
+        .flatten()
+        .map(|n| n.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let width = (widest + 2) & !1;
+    let lines: Vec<String> = rows
+        .iter()
+        .enumerate()
+        .map(|(depth, row)| {
+            let mut line = " ".repeat(depth * width / 2);
+            for n in row {
+                line.push_str(&format!("{n:>width$}"));
+            }
+            line
+        })
+        .collect();
+
+    let indent = lines
+        .iter()
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .into_iter()
+        .map(|line| line[indent..].to_string())
+        .collect()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input<'a> = Vec<Sequence>;
+    type Answer = Number;
+
+    fn parse(input: &str) -> Result<Vec<Sequence>, ParseError> {
+        parse::finish(
+            DAY,
+            input,
+            lines(numbers.map(|numbers| Sequence { numbers })),
+        )
+    }
+
+    fn part1(input: &Vec<Sequence>) -> anyhow::Result<Number> {
+        Ok(input.iter().map(|s| s.get_next()).sum())
+    }
+
+    fn part2(input: &Vec<Sequence>) -> anyhow::Result<Number> {
+        Ok(input.iter().map(|s| s.get_previous()).sum())
+    }
+
+    fn explain(input: &Vec<Sequence>, part: Part) -> anyhow::Result<Vec<ExplainStep>> {
+        Ok(input
+            .iter()
+            .enumerate()
+            .map(|(idx, s)| {
+                let rows = s.pyramid(part == Part::Two);
+                let mut lines = draw_pyramid(&rows);
+                lines.push(match part {
+                    Part::One => format!("next value {}", rows[0].last().unwrap()),
+                    Part::Two => format!("previous value {}", rows[0][0]),
+                });
+                ExplainStep {
+                    subject: format!("sequence {}", idx + 1),
+                    lines,
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn explains_pyramids() {
+        let sequences = Day9::parse(EXAMPLE).unwrap();
+
+        let steps = Day9::explain(&sequences, Part::One).unwrap();
+        assert_eq!(
+            steps[1].lines,
+            [
+                "1   3   6  10  15  21  28",
+                "  2   3   4   5   6   7",
+                "    1   1   1   1   1",
+                "      0   0   0   0",
+                "next value 28",
+            ]
+        );
+        let steps = Day9::explain(&sequences, Part::Two).unwrap();
+        assert_eq!(
+            steps[0].lines,
+            [
+                "-3   0   3   6   9  12  15",
+                "   3   3   3   3   3   3",
+                "     0   0   0   0   0",
+                "previous value -3"
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_number() {
+        let err = Day9::parse("0 3 6\n1 3 six").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    fn eval(coefficients: &[Number], x: Number) -> Number {
+        coefficients.iter().rev().fold(0, |acc, c| acc * x + c)
+    }
+
+    proptest! {
+        #[test]
+        fn extrapolates_polynomials(
+            coefficients in prop::collection::vec(-10..=10 as Number, 1..=6),
+            extra in 0..10usize,
+        ) {
+            let len = coefficients.len() + extra;
+            let sequence = Sequence {
+                numbers: (0..len as Number).map(|x| eval(&coefficients, x)).collect(),
+            };
+            prop_assert_eq!(sequence.get_next(), eval(&coefficients, len as Number));
+            prop_assert_eq!(sequence.get_previous(), eval(&coefficients, -1));
+        }
+    }
+}
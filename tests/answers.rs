@@ -0,0 +1,45 @@
+use std::{env, fs};
+
+use aoc23::{answers::Answers, inputs};
+
+/// Runs every registered day against its real input and compares the result
+/// with `answers/<year>.toml`. Anything that can't be checked counts as a failure.
+#[test]
+fn real_inputs() {
+    let answers = Answers::load(aoc23::answers::DEFAULT_DIR).unwrap();
+    let check_slow = env::var_os("AOC_SLOW").is_some();
+
+    let mut failures = Vec::new();
+    for day in aoc23::days() {
+        let path = inputs::path(day.year, day.day);
+        let Ok(input) = fs::read_to_string(&path) else {
+            failures.push(format!("day {}: missing input {}", day.day, path.display()));
+            continue;
+        };
+
+        for &part in day.parts() {
+            let Some(expected) = answers.get(day.year, day.day, part) else {
+                failures.push(format!("day {} part {part}: no expected answer", day.day));
+                continue;
+            };
+            if answers.is_slow(day.year, day.day, part) && !check_slow {
+                eprintln!(
+                    "day {} part {part}: skipped as slow, set AOC_SLOW=1 to check it",
+                    day.day
+                );
+                continue;
+            }
+
+            match day.run(&input, part) {
+                Ok(run) if run.answer == expected => {}
+                Ok(run) => failures.push(format!(
+                    "day {} part {part}: expected {expected}, got {}",
+                    day.day, run.answer
+                )),
+                Err(e) => failures.push(format!("day {} part {part}: {e}", day.day)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,64 @@
+use std::{env, fs};
+
+use aoc23::fixtures;
+
+/// Runs every example under `tests/fixtures` and compares the results with
+/// its sidecar. Every registered day needs at least one example. Setting
+/// `AOC_EXAMPLES=2023/day10` only checks that day.
+#[test]
+fn examples() {
+    let only = env::var("AOC_EXAMPLES").ok();
+    let wanted = |year: u16, day: u8| {
+        only.as_ref()
+            .is_none_or(|only| *only == format!("{year}/day{day}"))
+    };
+    let fixtures: Vec<_> = fixtures::discover(fixtures::DEFAULT_DIR)
+        .unwrap()
+        .into_iter()
+        .filter(|fixture| wanted(fixture.year, fixture.day))
+        .collect();
+
+    let mut failures = Vec::new();
+    for day in aoc23::days().filter(|day| wanted(day.year, day.day)) {
+        if !fixtures
+            .iter()
+            .any(|fixture| (fixture.year, fixture.day) == (day.year, day.day))
+        {
+            failures.push(format!("{} day {}: no examples", day.year, day.day));
+        }
+    }
+    if fixtures.is_empty() && failures.is_empty() {
+        failures.push(format!(
+            "no examples for {}",
+            only.as_deref().unwrap_or("any day")
+        ));
+    }
+
+    for fixture in &fixtures {
+        let name = format!("{} day {} {}", fixture.year, fixture.day, fixture.name);
+        let Some(day) = aoc23::get_day(fixture.year, fixture.day) else {
+            failures.push(format!("{name}: no such day"));
+            continue;
+        };
+        let input = fs::read_to_string(&fixture.input).unwrap();
+
+        for check in &fixture.checks {
+            let part = check.part;
+            let params = if check.params.is_empty() {
+                String::new()
+            } else {
+                format!(" with {:?}", check.params)
+            };
+            match day.solve(&input, part, &check.params) {
+                Ok(answer) if answer == check.expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{name} part {part}{params}: expected {}, got {answer}",
+                    check.expected
+                )),
+                Err(e) => failures.push(format!("{name} part {part}{params}: {e}")),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}